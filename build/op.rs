@@ -189,6 +189,14 @@ pub fn write_op_macro() -> ::std::io::Result<()> {
             n_args: 1,
             op_type: Function,
         },
+        Op {
+            token: "neg",
+            operator: "Negate",
+            example: ("neg(U5)", "N5"),
+            precedence: !0,
+            n_args: 1,
+            op_type: Function,
+        },
         Op {
             token: "cube",
             operator: "Cube",
@@ -229,6 +237,30 @@ pub fn write_op_macro() -> ::std::io::Result<()> {
             n_args: 1,
             op_type: Function,
         },
+        Op {
+            token: "ceil_log2",
+            operator: "CeilLog2",
+            example: ("ceil_log2(U9)", "U4"),
+            precedence: !0,
+            n_args: 1,
+            op_type: Function,
+        },
+        Op {
+            token: "log",
+            operator: "Log",
+            example: ("log(U1000, U10)", "U3"),
+            precedence: !0,
+            n_args: 2,
+            op_type: Function,
+        },
+        Op {
+            token: "ceil_log",
+            operator: "CeilLog",
+            example: ("ceil_log(U1000, U3)", "U7"),
+            precedence: !0,
+            n_args: 2,
+            op_type: Function,
+        },
         Op {
             token: "gcd",
             operator: "Gcf",
@@ -237,6 +269,158 @@ pub fn write_op_macro() -> ::std::io::Result<()> {
             n_args: 2,
             op_type: Function,
         },
+        Op {
+            token: "not",
+            operator: "BitNot",
+            example: ("not(P5)", "N6"),
+            precedence: !0,
+            n_args: 1,
+            op_type: Function,
+        },
+        Op {
+            token: "lcm",
+            operator: "Lcmf",
+            example: ("lcm(U4, U6)", "U12"),
+            precedence: !0,
+            n_args: 2,
+            op_type: Function,
+        },
+        Op {
+            token: "div_ceil",
+            operator: "QuotCeil",
+            example: ("div_ceil(U100, U64)", "U2"),
+            precedence: !0,
+            n_args: 2,
+            op_type: Function,
+        },
+        Op {
+            token: "div_floor",
+            operator: "QuotFloor",
+            example: ("div_floor(N7, P2)", "N4"),
+            precedence: !0,
+            n_args: 2,
+            op_type: Function,
+        },
+        Op {
+            token: "div_euclid",
+            operator: "QuotEuclid",
+            example: ("div_euclid(N7, N2)", "P4"),
+            precedence: !0,
+            n_args: 2,
+            op_type: Function,
+        },
+        Op {
+            token: "rem_euclid",
+            operator: "ModEuclid",
+            example: ("rem_euclid(N7, N2)", "P1"),
+            precedence: !0,
+            n_args: 2,
+            op_type: Function,
+        },
+        Op {
+            token: "fact",
+            operator: "Fact",
+            example: ("fact(U5)", "U120"),
+            precedence: !0,
+            n_args: 1,
+            op_type: Function,
+        },
+        Op {
+            token: "choose",
+            operator: "Choose",
+            example: ("choose(U10, U3)", "U120"),
+            precedence: !0,
+            n_args: 2,
+            op_type: Function,
+        },
+        Op {
+            token: "perm",
+            operator: "Perm",
+            example: ("perm(U5, U3)", "U60"),
+            precedence: !0,
+            n_args: 2,
+            op_type: Function,
+        },
+        Op {
+            token: "saturating_sub",
+            operator: "SaturatingDiff",
+            example: ("saturating_sub(U2, U3)", "U0"),
+            precedence: !0,
+            n_args: 2,
+            op_type: Function,
+        },
+        Op {
+            token: "abs_diff",
+            operator: "AbsDifference",
+            example: ("abs_diff(N2, P3)", "P5"),
+            precedence: !0,
+            n_args: 2,
+            op_type: Function,
+        },
+        Op {
+            token: "clamp",
+            operator: "Clamped",
+            example: ("clamp(P7, N3, P5)", "P5"),
+            precedence: !0,
+            n_args: 3,
+            op_type: Function,
+        },
+        Op {
+            token: "if_else",
+            operator: "IfElse",
+            example: ("if_else(P2 < P3, P2, P3)", "P2"),
+            precedence: !0,
+            n_args: 3,
+            op_type: Function,
+        },
+        Op {
+            token: "signum",
+            operator: "Sgn",
+            example: ("signum(N3)", "N1"),
+            precedence: !0,
+            n_args: 1,
+            op_type: Function,
+        },
+        Op {
+            token: "is_negative",
+            operator: "IsNegativeOut",
+            example: ("is_negative(N3)", "True"),
+            precedence: !0,
+            n_args: 1,
+            op_type: Function,
+        },
+        Op {
+            token: "is_positive",
+            operator: "IsPositiveOut",
+            example: ("is_positive(N3)", "False"),
+            precedence: !0,
+            n_args: 1,
+            op_type: Function,
+        },
+        Op {
+            token: "is_zero",
+            operator: "IsZeroOut",
+            example: ("is_zero(U0)", "True"),
+            precedence: !0,
+            n_args: 1,
+            op_type: Function,
+        },
+        Op {
+            token: "is_even",
+            operator: "IsEvenOut",
+            example: ("is_even(P4)", "True"),
+            precedence: !0,
+            n_args: 1,
+            op_type: Function,
+        },
+        Op {
+            token: "is_odd",
+            operator: "IsOddOut",
+            example: ("is_odd(P4)", "False"),
+            precedence: !0,
+            n_args: 1,
+            op_type: Function,
+        },
     ];
 
     use std::io::Write;
@@ -273,6 +457,29 @@ The full list of supported operators and functions is as follows:
 
 {}
 
+Conditionals may also be written as `if cond {{ then }} else {{ other }}`, which is shorthand for
+`if_else(cond, then, other)`:
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {{
+assert_type_eq!(op!(if P2 < P3 {{ P2 }} else {{ P3 }} + P1), P3);
+# }}
+```
+
+`Unsigned` and `Integer` operands may be mixed, giving an `Integer`. Negation is written with the
+`neg` function:
+
+```rust
+# #[macro_use] extern crate typenum;
+# use typenum::*;
+# fn main() {{
+assert_type_eq!(op!(min(U5, N2)), N2);
+assert_type_eq!(op!(neg(U5) + P2), N3);
+# }}
+```
+
 They all expand to type aliases defined in the `operator_aliases` module. Here is an expanded list,
 including examples:
 
@@ -328,6 +535,26 @@ macro_rules! op {{
     // -----------------------------------------------------------------------------------------
     // Stage 1: There are tokens to be read:
 
+    // -------
+    // Case 0: Token is "if" => Collect the condition up to the "{ then } else { other }"
+    // blocks, and rewrite the whole conditional as a call to "if_else":
+    write!(
+        f,
+        "
+(@stack[$($stack:ident,)*] @queue[$($queue:ident,)*] @tail: if $($tail:tt)*) => (
+    __op_internal__!(@if[] @stack[$($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);
+(@if[$($cond:tt)*] @stack[$($stack:ident,)*] @queue[$($queue:ident,)*]
+ @tail: {{ $($then:tt)* }} else {{ $($other:tt)* }} $($tail:tt)*) => (
+    __op_internal__!(@stack[$($stack,)*] @queue[$($queue,)*]
+                     @tail: if_else(($($cond)*), ($($then)*), ($($other)*)) $($tail)*)
+);
+(@if[$($cond:tt)*] @stack[$($stack:ident,)*] @queue[$($queue:ident,)*]
+ @tail: $next:tt $($tail:tt)*) => (
+    __op_internal__!(@if[$($cond)* $next] @stack[$($stack,)*] @queue[$($queue,)*] @tail: $($tail)*)
+);"
+    )?;
+
     // -------
     // Case 1: Token is a function => Push it onto the stack:
     for fun in ops.iter().filter(|f| f.op_type == Function) {
@@ -498,6 +725,18 @@ macro_rules! op {{
 
     // -----------------------------------------------------------------------------------------
     // Stage 3: Evaluate in Reverse Polish Notation
+    // Operators with 3 args:
+    for op in ops.iter().filter(|op| op.n_args == 3) {
+        // Note: The stack is reversed here too, so the first argument is the deepest
+        write!(
+            f,
+            "
+(@eval @stack[$a:ty, $b:ty, $c:ty, $($stack:ty,)*] @input[{op}, $($tail:ident,)*]) => (
+    __op_internal__!(@eval @stack[$crate::{op}<$c, $b, $a>, $($stack,)*] @input[$($tail,)*])
+);",
+            op = op.operator
+        )?;
+    }
     // Operators / Operators with 2 args:
     for op in ops.iter().filter(|op| op.n_args == 2) {
         // Note: We have to switch $a and $b here, otherwise non-commutative functions are backwards
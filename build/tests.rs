@@ -1,4 +1,4 @@
-use std::{env, fmt, fs, io, path};
+use std::{cmp, env, fmt, fs, io, path};
 
 use super::{gen_int, gen_uint};
 
@@ -26,6 +26,37 @@ fn gcdu(mut a: u64, mut b: u64) -> u64 {
     b
 }
 
+/// Computes the least common multiple of two integers.
+fn lcmi(a: i64, b: i64) -> i64 {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        (a / gcdi(a, b) * b).abs()
+    }
+}
+
+fn lcmu(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        a / gcdu(a, b) * b
+    }
+}
+
+/// Computes the number of `k`-permutations of `n` items.
+fn permu(n: u64, k: u64) -> u64 {
+    if k > n {
+        0
+    } else {
+        (n - k + 1..n + 1).product()
+    }
+}
+
+/// Computes the binomial coefficient.
+fn chooseu(n: u64, k: u64) -> u64 {
+    permu(n, k) / (1..k + 1).product::<u64>()
+}
+
 fn sign(i: i64) -> char {
     use std::cmp::Ordering::*;
     match i.cmp(&0) {
@@ -151,6 +182,108 @@ fn int_binary_test(left: i64, operator: &'static str, right: i64, result: i64) -
     }
 }
 
+struct IntUIntBinaryTest {
+    a: i64,
+    op: &'static str,
+    b: u64,
+    r: i64,
+}
+
+impl fmt::Display for IntUIntBinaryTest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "
+#[test]
+#[allow(non_snake_case)]
+fn test_{sa}{a}_{op}_{b}() {{
+    type A = {gen_a};
+    type B = {gen_b};
+    type {sr}{r} = {result};
+
+    #[allow(non_camel_case_types)]
+    type {sa}{a}{op}U{b} = <<A as {op}<B>>::Output as Same<{sr}{r}>>::Output;
+
+    assert_eq!(<{sa}{a}{op}U{b} as Integer>::to_i64(), <{sr}{r} as Integer>::to_i64());
+}}",
+            gen_a = gen_int(self.a),
+            gen_b = gen_uint(self.b),
+            r = self.r.abs(),
+            sr = sign(self.r),
+            result = gen_int(self.r),
+            a = self.a.abs(),
+            b = self.b,
+            sa = sign(self.a),
+            op = self.op
+        )
+    }
+}
+
+fn int_uint_binary_test(
+    left: i64,
+    operator: &'static str,
+    right: u64,
+    result: i64,
+) -> IntUIntBinaryTest {
+    IntUIntBinaryTest {
+        a: left,
+        op: operator,
+        b: right,
+        r: result,
+    }
+}
+
+struct UIntIntBinaryTest {
+    a: u64,
+    op: &'static str,
+    b: i64,
+    r: i64,
+}
+
+impl fmt::Display for UIntIntBinaryTest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "
+#[test]
+#[allow(non_snake_case)]
+fn test_{a}_{op}_{sb}{b}() {{
+    type A = {gen_a};
+    type B = {gen_b};
+    type {sr}{r} = {result};
+
+    #[allow(non_camel_case_types)]
+    type U{a}{op}{sb}{b} = <<A as {op}<B>>::Output as Same<{sr}{r}>>::Output;
+
+    assert_eq!(<U{a}{op}{sb}{b} as Integer>::to_i64(), <{sr}{r} as Integer>::to_i64());
+}}",
+            gen_a = gen_uint(self.a),
+            gen_b = gen_int(self.b),
+            r = self.r.abs(),
+            sr = sign(self.r),
+            result = gen_int(self.r),
+            a = self.a,
+            b = self.b.abs(),
+            sb = sign(self.b),
+            op = self.op
+        )
+    }
+}
+
+fn uint_int_binary_test(
+    left: u64,
+    operator: &'static str,
+    right: i64,
+    result: i64,
+) -> UIntIntBinaryTest {
+    UIntIntBinaryTest {
+        a: left,
+        op: operator,
+        b: right,
+        r: result,
+    }
+}
+
 struct IntUnaryTest {
     op: &'static str,
     a: i64,
@@ -235,6 +368,89 @@ fn test_{sa}{a}_Cmp_{sb}{b}() {{
     )
 }
 
+fn uint_int_cmp_test(a: u64, b: i64) -> String {
+    format!(
+        "
+#[test]
+#[allow(non_snake_case)]
+fn test_{a}_Cmp_{sb}{b}() {{
+    type A = {gen_a};
+    type B = {gen_b};
+
+    #[allow(non_camel_case_types)]
+    type U{a}Cmp{sb}{b} = <A as Cmp<B>>::Output;
+    assert_eq!(<U{a}Cmp{sb}{b} as Ord>::to_ordering(), Ordering::{result:?});
+}}",
+        a = a,
+        b = b.abs(),
+        sb = sign(b),
+        gen_a = gen_uint(a),
+        gen_b = gen_int(b),
+        result = (a as i64).cmp(&b)
+    )
+}
+
+fn int_uint_cmp_test(a: i64, b: u64) -> String {
+    format!(
+        "
+#[test]
+#[allow(non_snake_case)]
+fn test_{sa}{a}_Cmp_{b}() {{
+    type A = {gen_a};
+    type B = {gen_b};
+
+    #[allow(non_camel_case_types)]
+    type {sa}{a}CmpU{b} = <A as Cmp<B>>::Output;
+    assert_eq!(<{sa}{a}CmpU{b} as Ord>::to_ordering(), Ordering::{result:?});
+}}",
+        a = a.abs(),
+        b = b,
+        sa = sign(a),
+        gen_a = gen_int(a),
+        gen_b = gen_uint(b),
+        result = a.cmp(&(b as i64))
+    )
+}
+
+fn int_if_else_test(a: i64, b: i64) -> String {
+    let min = cmp::min(a, b);
+    let nested = if a < b {
+        if a < 0 {
+            -1
+        } else {
+            1
+        }
+    } else {
+        0
+    };
+    format!(
+        "
+#[test]
+#[allow(non_snake_case)]
+fn test_{sa}{a}_IfElse_{sb}{b}() {{
+    type A = {gen_a};
+    type B = {gen_b};
+
+    assert_type_eq!(op!(if A < B {{ A }} else {{ B }}), {gen_min});
+    assert_type_eq!(op!(if_else(A < B, A, B)), {gen_min});
+    assert_type_eq!(op!(P1 + if A < B {{ A }} else {{ B }}), {gen_min_plus_one});
+    assert_type_eq!(
+        op!(if A < B {{ if A < Z0 {{ N1 }} else {{ P1 }} }} else {{ Z0 }}),
+        {gen_nested}
+    );
+}}",
+        a = a.abs(),
+        b = b.abs(),
+        sa = sign(a),
+        sb = sign(b),
+        gen_a = gen_int(a),
+        gen_b = gen_int(b),
+        gen_min = gen_int(min),
+        gen_min_plus_one = gen_int(min + 1),
+        gen_nested = gen_int(nested)
+    )
+}
+
 // Allow for rustc 1.22 compatibility.
 #[allow(bare_trait_objects)]
 pub fn build_tests() -> Result<(), Box<::std::error::Error>> {
@@ -258,7 +474,6 @@ use std::cmp::Ordering;
 use typenum::*;
 ",
     )?;
-    use std::cmp;
     // uint operators:
     for (a, b) in uints {
         write!(writer, "{}", uint_binary_test(a, "BitAnd", b, a & b))?;
@@ -270,6 +485,25 @@ use typenum::*;
         write!(writer, "{}", uint_binary_test(a, "Min", b, cmp::min(a, b)))?;
         write!(writer, "{}", uint_binary_test(a, "Max", b, cmp::max(a, b)))?;
         write!(writer, "{}", uint_binary_test(a, "Gcd", b, gcdu(a, b)))?;
+        write!(writer, "{}", uint_binary_test(a, "Lcm", b, lcmu(a, b)))?;
+        write!(
+            writer,
+            "{}",
+            uint_binary_test(a, "Permutations", b, permu(a, b))
+        )?;
+        write!(
+            writer,
+            "{}",
+            uint_binary_test(a, "Binomial", b, chooseu(a, b))
+        )?;
+        let abs_diff = if a > b { a - b } else { b - a };
+        write!(writer, "{}", uint_binary_test(a, "AbsDiff", b, abs_diff))?;
+        let saturating_diff = a.saturating_sub(b);
+        write!(
+            writer,
+            "{}",
+            uint_binary_test(a, "SaturatingSub", b, saturating_diff)
+        )?;
         if a >= b {
             write!(writer, "{}", uint_binary_test(a, "Sub", b, a - b))?;
         }
@@ -280,6 +514,9 @@ use typenum::*;
             if a % b == 0 {
                 write!(writer, "{}", uint_binary_test(a, "PartialDiv", b, a / b))?;
             }
+            write!(writer, "{}", uint_binary_test(a, "DivFloor", b, a / b))?;
+            let div_ceil = (a + b - 1) / b;
+            write!(writer, "{}", uint_binary_test(a, "DivCeil", b, div_ceil))?;
         }
         write!(writer, "{}", uint_binary_test(a, "Pow", b, a.pow(b as u32)))?;
         write!(writer, "{}", uint_cmp_test(a, b))?;
@@ -291,13 +528,40 @@ use typenum::*;
         write!(writer, "{}", int_binary_test(a, "Mul", b, a * b))?;
         write!(writer, "{}", int_binary_test(a, "Min", b, cmp::min(a, b)))?;
         write!(writer, "{}", int_binary_test(a, "Max", b, cmp::max(a, b)))?;
+        write!(writer, "{}", int_binary_test(a, "BitAnd", b, a & b))?;
+        write!(writer, "{}", int_binary_test(a, "BitOr", b, a | b))?;
+        write!(writer, "{}", int_binary_test(a, "BitXor", b, a ^ b))?;
         write!(writer, "{}", int_binary_test(a, "Gcd", b, gcdi(a, b)))?;
+        write!(writer, "{}", int_binary_test(a, "Lcm", b, lcmi(a, b)))?;
+        write!(
+            writer,
+            "{}",
+            int_binary_test(a, "AbsDiff", b, (a - b).abs())
+        )?;
         if b != 0 {
             write!(writer, "{}", int_binary_test(a, "Div", b, a / b))?;
             write!(writer, "{}", int_binary_test(a, "Rem", b, a % b))?;
             if a % b == 0 {
                 write!(writer, "{}", int_binary_test(a, "PartialDiv", b, a / b))?;
             }
+            // `i64::rem_euclid` and friends are newer than our minimum supported Rust version.
+            let (q, r) = (a / b, a % b);
+            let rem_euclid = (r + b.abs()) % b.abs();
+            let div_euclid = (a - rem_euclid) / b;
+            let div_floor = if r != 0 && (r < 0) != (b < 0) {
+                q - 1
+            } else {
+                q
+            };
+            let div_ceil = if r != 0 && (r < 0) == (b < 0) {
+                q + 1
+            } else {
+                q
+            };
+            write!(writer, "{}", int_binary_test(a, "RemEuclid", b, rem_euclid))?;
+            write!(writer, "{}", int_binary_test(a, "DivEuclid", b, div_euclid))?;
+            write!(writer, "{}", int_binary_test(a, "DivFloor", b, div_floor))?;
+            write!(writer, "{}", int_binary_test(a, "DivCeil", b, div_ceil))?;
         }
         if b >= 0 || a.abs() == 1 {
             let result = if b < 0 {
@@ -314,12 +578,106 @@ use typenum::*;
             write!(writer, "{}", int_binary_test(a, "Pow", b, result))?;
         }
         write!(writer, "{}", int_cmp_test(a, b))?;
+        write!(writer, "{}", int_if_else_test(a, b))?;
+    }
+
+    // int and uint operators:
+    for a in -high..high + 1 {
+        for b in 0..high as u64 + 1 {
+            write!(writer, "{}", int_uint_binary_test(a, "Shl", b, a << b))?;
+            write!(writer, "{}", int_uint_binary_test(a, "Shr", b, a >> b))?;
+            write!(
+                writer,
+                "{}",
+                int_uint_binary_test(a, "Add", b, a + b as i64)
+            )?;
+            write!(
+                writer,
+                "{}",
+                int_uint_binary_test(a, "Sub", b, a - b as i64)
+            )?;
+            write!(
+                writer,
+                "{}",
+                int_uint_binary_test(a, "Mul", b, a * b as i64)
+            )?;
+            write!(
+                writer,
+                "{}",
+                uint_int_binary_test(b, "Add", a, b as i64 + a)
+            )?;
+            write!(
+                writer,
+                "{}",
+                uint_int_binary_test(b, "Sub", a, b as i64 - a)
+            )?;
+            write!(
+                writer,
+                "{}",
+                uint_int_binary_test(b, "Mul", a, b as i64 * a)
+            )?;
+            let (ai, bi) = (a, b as i64);
+            write!(
+                writer,
+                "{}",
+                int_uint_binary_test(a, "Min", b, cmp::min(ai, bi))
+            )?;
+            write!(
+                writer,
+                "{}",
+                int_uint_binary_test(a, "Max", b, cmp::max(ai, bi))
+            )?;
+            write!(
+                writer,
+                "{}",
+                uint_int_binary_test(b, "Min", a, cmp::min(bi, ai))
+            )?;
+            write!(
+                writer,
+                "{}",
+                uint_int_binary_test(b, "Max", a, cmp::max(bi, ai))
+            )?;
+            if b != 0 {
+                write!(writer, "{}", int_uint_binary_test(a, "Div", b, ai / bi))?;
+                write!(writer, "{}", int_uint_binary_test(a, "Rem", b, ai % bi))?;
+                if ai % bi == 0 {
+                    write!(
+                        writer,
+                        "{}",
+                        int_uint_binary_test(a, "PartialDiv", b, ai / bi)
+                    )?;
+                }
+            }
+            if a != 0 {
+                write!(writer, "{}", uint_int_binary_test(b, "Div", a, bi / ai))?;
+                write!(writer, "{}", uint_int_binary_test(b, "Rem", a, bi % ai))?;
+                if bi % ai == 0 {
+                    write!(
+                        writer,
+                        "{}",
+                        uint_int_binary_test(b, "PartialDiv", a, bi / ai)
+                    )?;
+                }
+            }
+            write!(writer, "{}", int_uint_cmp_test(a, b))?;
+            write!(writer, "{}", uint_int_cmp_test(b, a))?;
+        }
     }
 
     // int unary operators:
     for n in -high..high + 1 {
         write!(writer, "{}", int_unary_test("Neg", n, -n))?;
         write!(writer, "{}", int_unary_test("Abs", n, n.abs()))?;
+        write!(writer, "{}", int_unary_test("Not", n, !n))?;
+        write!(writer, "{}", int_unary_test("Signum", n, n.signum()))?;
+        if n >= 0 {
+            let sqrt = (0..=n).take_while(|r| r * r <= n).last().unwrap();
+            write!(writer, "{}", int_unary_test("SquareRoot", n, sqrt))?;
+        }
+        if n > 0 {
+            let log2 = 63 - n.leading_zeros() as i64;
+            write!(writer, "{}", int_unary_test("Logarithm2", n, log2))?;
+        }
     }
 
     writer.flush()?;
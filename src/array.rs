@@ -75,6 +75,37 @@ where
     }
 }
 
+// ---------------------------------------------------------------------------------------
+// Append to arrays
+use crate::private::{PrivateAppend, PrivateAppendOut};
+
+impl<V> PrivateAppend<V> for ATerm {
+    type Output = TArr<V, ATerm>;
+}
+
+impl<V, A, Vn> PrivateAppend<Vn> for TArr<V, A>
+where
+    A: PrivateAppend<Vn>,
+{
+    type Output = TArr<V, PrivateAppendOut<A, Vn>>;
+}
+
+// ---------------------------------------------------------------------------------------
+// Sum of the elements of an array
+use crate::private::{PrivateArraySum, PrivateArraySumOut};
+
+impl PrivateArraySum for ATerm {
+    type Output = U0;
+}
+
+impl<V, A> PrivateArraySum for TArr<V, A>
+where
+    A: PrivateArraySum,
+    V: Add<PrivateArraySumOut<A>>,
+{
+    type Output = Sum<V, PrivateArraySumOut<A>>;
+}
+
 // ---------------------------------------------------------------------------------------
 // Add arrays
 // Note that two arrays are only addable if they are the same length.
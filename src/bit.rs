@@ -6,11 +6,17 @@
 //!
 //! **Type operators** implemented:
 //!
-//! - From `core::ops`: `BitAnd`, `BitOr`, `BitXor`, and `Not`.
-//! - From `typenum`: `Same` and `Cmp`.
-
-use crate::{private::InternalMarker, Cmp, Equal, Greater, Less, NonZero, PowerOfTwo, Zero};
-use core::ops::{BitAnd, BitOr, BitXor, Not};
+//! - From `core::ops`: `BitAnd`, `BitOr`, `BitXor`, `Not`, and `Add`, `Sub`, and `Mul`, which
+//!   give an `Unsigned`.
+//! - From `typenum`: `Same`, `Cmp`, `Nand`, `Nor`, `Xnor`, `Implies`, `FullAdd`, and `FullSub`.
+
+use crate::{
+    consts::{P1, U0, U1, U2, Z0},
+    private::InternalMarker,
+    BitNot, Cmp, Equal, FullAdd, FullSub, Greater, Implies, Less, Nand, NonZero, Nor, PowerOfTwo,
+    UInt, UTerm, Xnor, Zero,
+};
+use core::ops::{Add, BitAnd, BitOr, BitXor, Mul, Not, Sub};
 
 pub use crate::marker_traits::Bit;
 
@@ -56,6 +62,10 @@ impl Bit for B0 {
     fn to_bool() -> bool {
         false
     }
+    #[inline]
+    fn select<T>(_then: T, otherwise: T) -> T {
+        otherwise
+    }
 }
 
 impl Bit for B1 {
@@ -74,6 +84,10 @@ impl Bit for B1 {
     fn to_bool() -> bool {
         true
     }
+    #[inline]
+    fn select<T>(then: T, _otherwise: T) -> T {
+        then
+    }
 }
 
 impl Zero for B0 {}
@@ -184,6 +198,188 @@ impl BitXor<B1> for B1 {
     }
 }
 
+/// Adding bits ( 0 + 0 = 0)
+impl Add<B0> for B0 {
+    type Output = U0;
+    #[inline]
+    fn add(self, _: B0) -> Self::Output {
+        UTerm
+    }
+}
+/// Adding bits ( 0 + 1 = 1)
+impl Add<B1> for B0 {
+    type Output = U1;
+    #[inline]
+    fn add(self, _: B1) -> Self::Output {
+        UInt::new()
+    }
+}
+/// Adding bits ( 1 + 0 = 1)
+impl Add<B0> for B1 {
+    type Output = U1;
+    #[inline]
+    fn add(self, _: B0) -> Self::Output {
+        UInt::new()
+    }
+}
+/// Adding bits ( 1 + 1 = 2)
+impl Add<B1> for B1 {
+    type Output = U2;
+    #[inline]
+    fn add(self, _: B1) -> Self::Output {
+        UInt::new()
+    }
+}
+
+/// Subtracting bits ( 0 - 0 = 0)
+impl Sub<B0> for B0 {
+    type Output = U0;
+    #[inline]
+    fn sub(self, _: B0) -> Self::Output {
+        UTerm
+    }
+}
+/// Subtracting bits ( 1 - 0 = 1)
+impl Sub<B0> for B1 {
+    type Output = U1;
+    #[inline]
+    fn sub(self, _: B0) -> Self::Output {
+        UInt::new()
+    }
+}
+/// Subtracting bits ( 1 - 1 = 0)
+impl Sub<B1> for B1 {
+    type Output = U0;
+    #[inline]
+    fn sub(self, _: B1) -> Self::Output {
+        UTerm
+    }
+}
+
+/// Multiplying with 0 ( 0 * B = 0)
+impl<Rhs: Bit> Mul<Rhs> for B0 {
+    type Output = U0;
+    #[inline]
+    fn mul(self, _: Rhs) -> Self::Output {
+        UTerm
+    }
+}
+/// Multiplying with 1 ( 1 * 0 = 0)
+impl Mul<B0> for B1 {
+    type Output = U0;
+    #[inline]
+    fn mul(self, _: B0) -> Self::Output {
+        UTerm
+    }
+}
+/// Multiplying with 1 ( 1 * 1 = 1)
+impl Mul<B1> for B1 {
+    type Output = U1;
+    #[inline]
+    fn mul(self, _: B1) -> Self::Output {
+        UInt::new()
+    }
+}
+
+/// Nand with 0 ( !(0 & B) = 1)
+impl<Rhs: Bit> Nand<Rhs> for B0 {
+    type Output = B1;
+}
+/// Nand with 1 ( !(1 & B) = !B)
+impl<Rhs: Bit + Not> Nand<Rhs> for B1
+where
+    BitNot<Rhs>: Bit,
+{
+    type Output = BitNot<Rhs>;
+}
+
+/// Nor with 0 ( !(0 | B) = !B)
+impl<Rhs: Bit + Not> Nor<Rhs> for B0
+where
+    BitNot<Rhs>: Bit,
+{
+    type Output = BitNot<Rhs>;
+}
+/// Nor with 1 ( !(1 | B) = 0)
+impl<Rhs: Bit> Nor<Rhs> for B1 {
+    type Output = B0;
+}
+
+/// Xnor with 0 ( !(0 ^ B) = !B)
+impl<Rhs: Bit + Not> Xnor<Rhs> for B0
+where
+    BitNot<Rhs>: Bit,
+{
+    type Output = BitNot<Rhs>;
+}
+/// Xnor with 1 ( !(1 ^ B) = B)
+impl<Rhs: Bit> Xnor<Rhs> for B1 {
+    type Output = Rhs;
+}
+
+/// Implication from 0 ( 0 -> B = 1)
+impl<Rhs: Bit> Implies<Rhs> for B0 {
+    type Output = B1;
+}
+/// Implication from 1 ( 1 -> B = B)
+impl<Rhs: Bit> Implies<Rhs> for B1 {
+    type Output = Rhs;
+}
+
+/// Full adder with `0 + 0 + C`
+impl<C: Bit> FullAdd<B0, C> for B0 {
+    type Output = C;
+    type Carry = B0;
+}
+/// Full adder with `0 + 1 + C`
+impl<C: Bit + Not> FullAdd<B1, C> for B0
+where
+    BitNot<C>: Bit,
+{
+    type Output = BitNot<C>;
+    type Carry = C;
+}
+/// Full adder with `1 + 0 + C`
+impl<C: Bit + Not> FullAdd<B0, C> for B1
+where
+    BitNot<C>: Bit,
+{
+    type Output = BitNot<C>;
+    type Carry = C;
+}
+/// Full adder with `1 + 1 + C`
+impl<C: Bit> FullAdd<B1, C> for B1 {
+    type Output = C;
+    type Carry = B1;
+}
+
+/// Full subtractor with `0 - 0 - B`
+impl<B: Bit> FullSub<B0, B> for B0 {
+    type Output = B;
+    type Borrow = B;
+}
+/// Full subtractor with `0 - 1 - B`
+impl<B: Bit + Not> FullSub<B1, B> for B0
+where
+    BitNot<B>: Bit,
+{
+    type Output = BitNot<B>;
+    type Borrow = B1;
+}
+/// Full subtractor with `1 - 0 - B`
+impl<B: Bit + Not> FullSub<B0, B> for B1
+where
+    BitNot<B>: Bit,
+{
+    type Output = BitNot<B>;
+    type Borrow = B0;
+}
+/// Full subtractor with `1 - 1 - B`
+impl<B: Bit> FullSub<B1, B> for B1 {
+    type Output = B;
+    type Borrow = B;
+}
+
 #[cfg(tests)]
 mod tests {
     // macro for testing operation results. Uses `Same` to ensure the types are equal and
@@ -317,6 +513,75 @@ impl Max<B1> for B1 {
     }
 }
 
+use crate::If;
+
+impl<Then, Else> If<Then, Else> for B0 {
+    type Output = Else;
+}
+impl<Then, Else> If<Then, Else> for B1 {
+    type Output = Then;
+}
+
+use crate::{BitToInteger, BitToUnsigned};
+
+impl BitToUnsigned for B0 {
+    type Output = U0;
+}
+impl BitToUnsigned for B1 {
+    type Output = U1;
+}
+
+impl BitToInteger for B0 {
+    type Output = Z0;
+}
+impl BitToInteger for B1 {
+    type Output = P1;
+}
+
+use crate::{IsEven, IsNegative, IsOdd, IsPositive, IsZero, Signum};
+
+impl Signum for B0 {
+    type Output = Z0;
+}
+impl Signum for B1 {
+    type Output = P1;
+}
+
+impl IsNegative for B0 {
+    type Output = B0;
+}
+impl IsNegative for B1 {
+    type Output = B0;
+}
+
+impl IsPositive for B0 {
+    type Output = B0;
+}
+impl IsPositive for B1 {
+    type Output = B1;
+}
+
+impl IsZero for B0 {
+    type Output = B1;
+}
+impl IsZero for B1 {
+    type Output = B0;
+}
+
+impl IsEven for B0 {
+    type Output = B1;
+}
+impl IsEven for B1 {
+    type Output = B0;
+}
+
+impl IsOdd for B0 {
+    type Output = B0;
+}
+impl IsOdd for B1 {
+    type Output = B1;
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -334,4 +599,103 @@ mod tests {
             let _: B1 = <B1 as Bit>::new();
         }
     }
+
+    #[test]
+    fn bit_predicates() {
+        use crate::{
+            assert_type_eq, IsEvenOut, IsNegativeOut, IsOddOut, IsPositiveOut, IsZeroOut, Sgn, B0,
+            B1, P1, Z0,
+        };
+
+        assert_type_eq!(Sgn<B0>, Z0);
+        assert_type_eq!(Sgn<B1>, P1);
+        assert_type_eq!(IsNegativeOut<B1>, B0);
+        assert_type_eq!(IsPositiveOut<B1>, B1);
+        assert_type_eq!(IsZeroOut<B0>, B1);
+        assert_type_eq!(IsEvenOut<B0>, B1);
+        assert_type_eq!(IsOddOut<B0>, B0);
+    }
+
+    #[test]
+    fn bit_if() {
+        use crate::{assert_type_eq, Bit, IfElse, B0, B1, U1, U2};
+
+        assert_type_eq!(IfElse<B1, U1, U2>, U1);
+        assert_type_eq!(IfElse<B0, U1, U2>, U2);
+        assert_eq!(B1::select(1, 2), 1);
+        assert_eq!(B0::select(1, 2), 2);
+    }
+
+    #[test]
+    fn bit_arithmetic() {
+        use crate::{assert_type_eq, Diff, Prod, Sum, B0, B1, U0, U1, U2};
+
+        assert_type_eq!(Sum<B0, B0>, U0);
+        assert_type_eq!(Sum<B0, B1>, U1);
+        assert_type_eq!(Sum<B1, B0>, U1);
+        assert_type_eq!(Sum<B1, B1>, U2);
+        assert_type_eq!(Diff<B0, B0>, U0);
+        assert_type_eq!(Diff<B1, B0>, U1);
+        assert_type_eq!(Diff<B1, B1>, U0);
+        assert_type_eq!(Prod<B0, B1>, U0);
+        assert_type_eq!(Prod<B1, B0>, U0);
+        assert_type_eq!(Prod<B1, B1>, U1);
+    }
+
+    #[test]
+    fn bit_logic() {
+        use crate::{assert_type_eq, ImpliesOut, NandOut, NorOut, XnorOut, B0, B1};
+
+        assert_type_eq!(NandOut<B0, B0>, B1);
+        assert_type_eq!(NandOut<B0, B1>, B1);
+        assert_type_eq!(NandOut<B1, B0>, B1);
+        assert_type_eq!(NandOut<B1, B1>, B0);
+        assert_type_eq!(NorOut<B0, B0>, B1);
+        assert_type_eq!(NorOut<B0, B1>, B0);
+        assert_type_eq!(NorOut<B1, B0>, B0);
+        assert_type_eq!(NorOut<B1, B1>, B0);
+        assert_type_eq!(XnorOut<B0, B0>, B1);
+        assert_type_eq!(XnorOut<B0, B1>, B0);
+        assert_type_eq!(XnorOut<B1, B0>, B0);
+        assert_type_eq!(XnorOut<B1, B1>, B1);
+        assert_type_eq!(ImpliesOut<B0, B0>, B1);
+        assert_type_eq!(ImpliesOut<B0, B1>, B1);
+        assert_type_eq!(ImpliesOut<B1, B0>, B0);
+        assert_type_eq!(ImpliesOut<B1, B1>, B1);
+    }
+
+    #[test]
+    fn full_adder() {
+        use crate::{Bit, FullBorrow, FullCarry, FullDiff, FullSum, B0, B1};
+
+        macro_rules! check {
+            ($a:ident, $b:ident, $c:ident) => {{
+                let (a, b, c) = ($a::U8 as i8, $b::U8 as i8, $c::U8 as i8);
+                let sum = <FullSum<$a, $b, $c>>::U8 as i8 + 2 * <FullCarry<$a, $b, $c>>::U8 as i8;
+                assert_eq!(sum, a + b + c);
+                let diff =
+                    <FullDiff<$a, $b, $c>>::U8 as i8 - 2 * <FullBorrow<$a, $b, $c>>::U8 as i8;
+                assert_eq!(diff, a - b - c);
+            }};
+        }
+
+        check!(B0, B0, B0);
+        check!(B0, B0, B1);
+        check!(B0, B1, B0);
+        check!(B0, B1, B1);
+        check!(B1, B0, B0);
+        check!(B1, B0, B1);
+        check!(B1, B1, B0);
+        check!(B1, B1, B1);
+    }
+
+    #[test]
+    fn bit_conversions() {
+        use crate::{assert_type_eq, BitAsInteger, BitAsUnsigned, B0, B1, P1, U0, U1, Z0};
+
+        assert_type_eq!(BitAsUnsigned<B0>, U0);
+        assert_type_eq!(BitAsUnsigned<B1>, U1);
+        assert_type_eq!(BitAsInteger<B0>, Z0);
+        assert_type_eq!(BitAsInteger<B1>, P1);
+    }
 }
@@ -3,16 +3,17 @@
 //!
 //! Type **operators** implemented:
 //!
-//! From `core::ops`: `Add`, `Sub`, `Mul`, `Div`, and `Rem`.
+//! From `core::ops`: `Add`, `Sub`, `Mul`, `Div`, `Rem`, `BitAnd`, `BitOr`, `BitXor`, `Not`,
+//! `Shl`, and `Shr`.
 //! From `typenum`: `Same`, `Cmp`, and `Pow`.
 //!
 //! Rather than directly using the structs defined in this module, it is recommended that
 //! you import and use the relevant aliases from the [consts](../consts/index.html) module.
 //!
-//! Note that operators that work on the underlying structure of the number are
-//! intentionally not implemented. This is because this implementation of signed integers
-//! does *not* use twos-complement, and implementing them would require making arbitrary
-//! choices, causing the results of such operators to be difficult to reason about.
+//! Signed integers are not stored in two's complement, but the bitwise operators act as if
+//! they were, on an infinitely wide two's complement number. They give the same results as
+//! the corresponding operators on Rust's primitive signed integers, so `N1 & P6 == P6` and
+//! `!P5 == N6`. Likewise, `Shr` is an arithmetic shift, which rounds toward negative infinity.
 //!
 //! # Example
 //! ```rust
@@ -30,11 +31,15 @@ pub use crate::marker_traits::Integer;
 use crate::{
     bit::{Bit, B0, B1},
     consts::{N1, P1, U0, U1},
-    private::{Internal, InternalMarker, PrivateDivInt, PrivateIntegerAdd, PrivateRem},
-    uint::{UInt, Unsigned},
-    Cmp, Equal, Greater, Less, NonZero, Pow, PowerOfTwo, ToInt, Zero,
+    private::{
+        Internal, InternalMarker, PrivateDivInt, PrivateIntegerAdd, PrivateRem, PrivateToSigned,
+        PrivateToSignedOut,
+    },
+    uint::{UInt, UTerm, Unsigned},
+    Add1, And, Cmp, Equal, Greater, Less, NonZero, Or, Pow, PowerOfTwo, Shleft, Shright, Sub1,
+    ToInt, Xor, Zero,
 };
-use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use core::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Not, Rem, Shl, Shr, Sub};
 
 /// Type-level signed integers with positive sign.
 #[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug, Default)]
@@ -774,6 +779,811 @@ impl_int_rem!(PInt, NInt, PInt);
 impl_int_rem!(NInt, PInt, NInt);
 impl_int_rem!(NInt, NInt, NInt);
 
+// ---------------------------------------------------------------------------------------
+// RemEuclid
+use crate::{Mod, ModEuclid, Negate, QuotEuclid, QuotFloor, RemEuclid, Sum};
+
+/// `M % |D|`, shifted into `[0, |D|)`: `((M % |D|) + |D|) % |D|`
+impl<M, U> RemEuclid<PInt<U>> for M
+where
+    M: Integer + Rem<PInt<U>>,
+    U: Unsigned + NonZero,
+    Mod<M, PInt<U>>: Add<PInt<U>>,
+    Sum<Mod<M, PInt<U>>, PInt<U>>: Rem<PInt<U>>,
+{
+    type Output = Mod<Sum<Mod<M, PInt<U>>, PInt<U>>, PInt<U>>;
+}
+
+/// `M % N` has the same result as `M % -N`
+impl<M, U> RemEuclid<NInt<U>> for M
+where
+    M: RemEuclid<PInt<U>>,
+    U: Unsigned + NonZero,
+{
+    type Output = ModEuclid<M, PInt<U>>;
+}
+
+// ---------------------------------------------------------------------------------------
+// DivEuclid
+use crate::{DivCeil, DivEuclid, DivFloor};
+
+/// `(M - M.rem_euclid(N)) / N`
+impl<M, U> DivEuclid<PInt<U>> for M
+where
+    M: Integer + RemEuclid<PInt<U>> + Sub<ModEuclid<M, PInt<U>>>,
+    U: Unsigned + NonZero,
+    Diff<M, ModEuclid<M, PInt<U>>>: Div<PInt<U>>,
+{
+    type Output = Quot<Diff<M, ModEuclid<M, PInt<U>>>, PInt<U>>;
+}
+
+/// `(M - M.rem_euclid(N)) / N`
+impl<M, U> DivEuclid<NInt<U>> for M
+where
+    M: Integer + RemEuclid<NInt<U>> + Sub<ModEuclid<M, NInt<U>>>,
+    U: Unsigned + NonZero,
+    Diff<M, ModEuclid<M, NInt<U>>>: Div<NInt<U>>,
+{
+    type Output = Quot<Diff<M, ModEuclid<M, NInt<U>>>, NInt<U>>;
+}
+
+// ---------------------------------------------------------------------------------------
+// DivFloor
+
+/// For a positive divisor, rounding down is Euclidean division
+impl<M, U> DivFloor<PInt<U>> for M
+where
+    M: Integer + DivEuclid<PInt<U>>,
+    U: Unsigned + NonZero,
+{
+    type Output = QuotEuclid<M, PInt<U>>;
+}
+
+/// `M / -N` rounded down is `-M / N` rounded down
+impl<M, U> DivFloor<NInt<U>> for M
+where
+    M: Integer + Neg,
+    U: Unsigned + NonZero,
+    Negate<M>: DivEuclid<PInt<U>>,
+{
+    type Output = QuotEuclid<Negate<M>, PInt<U>>;
+}
+
+// ---------------------------------------------------------------------------------------
+// DivCeil
+
+/// `M / N` rounded up is `-(-M / N)` rounded down
+impl<M, D> DivCeil<D> for M
+where
+    M: Integer + Neg,
+    D: Integer + NonZero,
+    Negate<M>: DivFloor<D>,
+    QuotFloor<Negate<M>, D>: Neg,
+{
+    type Output = Negate<QuotFloor<Negate<M>, D>>;
+}
+
+// ---------------------------------------------------------------------------------------
+// Two's complement helpers
+//
+// The bitwise operators treat a negative number `-n` as the infinitely wide two's complement
+// number `!(n - 1)`, so they are all expressed in terms of unsigned operations on `n - 1`.
+
+impl PrivateToSigned for UTerm {
+    type Output = Z0;
+    #[inline]
+    fn private_to_signed(self) -> Self::Output {
+        Z0
+    }
+}
+
+impl<U: Unsigned, B: Bit> PrivateToSigned for UInt<U, B> {
+    type Output = PInt<UInt<U, B>>;
+    #[inline]
+    fn private_to_signed(self) -> Self::Output {
+        PInt { n: self }
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// ToSigned and ToUnsigned
+use crate::{ToSigned, ToUnsigned};
+
+impl<N: Unsigned + PrivateToSigned> ToSigned for N {
+    type Output = PrivateToSignedOut<N>;
+}
+
+impl ToUnsigned for Z0 {
+    type Output = U0;
+}
+
+impl<U: Unsigned + NonZero> ToUnsigned for PInt<U> {
+    type Output = U;
+}
+
+// ---------------------------------------------------------------------------------------
+// Mixed Unsigned and Integer operations
+//
+// The unsigned operand is converted to the signed integer with the same value, so the results
+// are always `Integer`s. The payloads of `PInt` and `NInt` are spelled out as `UInt<U, B>` so
+// that inference never tries to nest them, which would overflow.
+
+/// `-U0 = Z0`
+impl Neg for UTerm {
+    type Output = Z0;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Z0
+    }
+}
+
+/// `-UInt = NInt`
+impl<U: Unsigned, B: Bit> Neg for UInt<U, B> {
+    type Output = NInt<UInt<U, B>>;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        NInt::new()
+    }
+}
+
+macro_rules! impl_uint_int_op {
+    ($Op:ident, $fun:ident; [$($gl:tt)*] $Lhs:ty; [$($gr:tt)*] $Rhs:ty) => {
+        /// `Unsigned op Integer = Integer`
+        impl<$($gl)* $($gr)*> $Op<$Rhs> for $Lhs
+        where
+            $Lhs: PrivateToSigned,
+            PrivateToSignedOut<$Lhs>: $Op<$Rhs>,
+        {
+            type Output = <PrivateToSignedOut<$Lhs> as $Op<$Rhs>>::Output;
+            #[inline]
+            fn $fun(self, rhs: $Rhs) -> Self::Output {
+                self.private_to_signed().$fun(rhs)
+            }
+        }
+    };
+}
+
+macro_rules! impl_int_uint_op {
+    ($Op:ident, $fun:ident; [$($gl:tt)*] $Lhs:ty; [$($gr:tt)*] $Rhs:ty) => {
+        /// `Integer op Unsigned = Integer`
+        impl<$($gl)* $($gr)*> $Op<$Rhs> for $Lhs
+        where
+            $Rhs: PrivateToSigned,
+            $Lhs: $Op<PrivateToSignedOut<$Rhs>>,
+        {
+            type Output = <$Lhs as $Op<PrivateToSignedOut<$Rhs>>>::Output;
+            #[inline]
+            fn $fun(self, rhs: $Rhs) -> Self::Output {
+                self.$fun(rhs.private_to_signed())
+            }
+        }
+    };
+}
+
+macro_rules! impl_mixed_ops {
+    ($($Op:ident $fun:ident),*) => {$(
+        impl_uint_int_op!($Op, $fun; [] UTerm; [] Z0);
+        impl_uint_int_op!($Op, $fun; [] UTerm; [Ur: Unsigned, Br: Bit,] PInt<UInt<Ur, Br>>);
+        impl_uint_int_op!($Op, $fun; [] UTerm; [Ur: Unsigned, Br: Bit,] NInt<UInt<Ur, Br>>);
+        impl_uint_int_op!($Op, $fun; [Ul: Unsigned, Bl: Bit,] UInt<Ul, Bl>; [] Z0);
+        impl_uint_int_op!($Op, $fun; [Ul: Unsigned, Bl: Bit,] UInt<Ul, Bl>; [Ur: Unsigned, Br: Bit,] PInt<UInt<Ur, Br>>);
+        impl_uint_int_op!($Op, $fun; [Ul: Unsigned, Bl: Bit,] UInt<Ul, Bl>; [Ur: Unsigned, Br: Bit,] NInt<UInt<Ur, Br>>);
+
+        impl_int_uint_op!($Op, $fun; [] Z0; [] UTerm);
+        impl_int_uint_op!($Op, $fun; [Pl: Unsigned, Pb: Bit,] PInt<UInt<Pl, Pb>>; [] UTerm);
+        impl_int_uint_op!($Op, $fun; [Pl: Unsigned, Pb: Bit,] NInt<UInt<Pl, Pb>>; [] UTerm);
+        impl_int_uint_op!($Op, $fun; [] Z0; [Ur: Unsigned, Br: Bit,] UInt<Ur, Br>);
+        impl_int_uint_op!($Op, $fun; [Pl: Unsigned, Pb: Bit,] PInt<UInt<Pl, Pb>>; [Ur: Unsigned, Br: Bit,] UInt<Ur, Br>);
+        impl_int_uint_op!($Op, $fun; [Pl: Unsigned, Pb: Bit,] NInt<UInt<Pl, Pb>>; [Ur: Unsigned, Br: Bit,] UInt<Ur, Br>);
+    )*};
+}
+
+impl_mixed_ops!(Add add, Sub sub, Mul mul);
+
+// Division only has impls for a non-zero divisor, so the zero divisors are left out here.
+macro_rules! impl_mixed_div_ops {
+    ($($Op:ident $fun:ident),*) => {$(
+        impl_uint_int_op!($Op, $fun; [] UTerm; [Ur: Unsigned, Br: Bit,] PInt<UInt<Ur, Br>>);
+        impl_uint_int_op!($Op, $fun; [] UTerm; [Ur: Unsigned, Br: Bit,] NInt<UInt<Ur, Br>>);
+        impl_uint_int_op!($Op, $fun; [Ul: Unsigned, Bl: Bit,] UInt<Ul, Bl>; [Ur: Unsigned, Br: Bit,] PInt<UInt<Ur, Br>>);
+        impl_uint_int_op!($Op, $fun; [Ul: Unsigned, Bl: Bit,] UInt<Ul, Bl>; [Ur: Unsigned, Br: Bit,] NInt<UInt<Ur, Br>>);
+
+        impl_int_uint_op!($Op, $fun; [] Z0; [Ur: Unsigned, Br: Bit,] UInt<Ur, Br>);
+        impl_int_uint_op!($Op, $fun; [Pl: Unsigned, Pb: Bit,] PInt<UInt<Pl, Pb>>; [Ur: Unsigned, Br: Bit,] UInt<Ur, Br>);
+        impl_int_uint_op!($Op, $fun; [Pl: Unsigned, Pb: Bit,] NInt<UInt<Pl, Pb>>; [Ur: Unsigned, Br: Bit,] UInt<Ur, Br>);
+    )*};
+}
+
+impl_mixed_div_ops!(Div div, Rem rem);
+
+// `Integer: PartialDiv<Unsigned>` is already covered by the blanket `PartialDiv` impl.
+impl_uint_int_op!(PartialDiv, partial_div; [] UTerm; [Ur: Unsigned, Br: Bit,] PInt<UInt<Ur, Br>>);
+impl_uint_int_op!(PartialDiv, partial_div; [] UTerm; [Ur: Unsigned, Br: Bit,] NInt<UInt<Ur, Br>>);
+impl_uint_int_op!(PartialDiv, partial_div; [Ul: Unsigned, Bl: Bit,] UInt<Ul, Bl>; [Ur: Unsigned, Br: Bit,] PInt<UInt<Ur, Br>>);
+impl_uint_int_op!(PartialDiv, partial_div; [Ul: Unsigned, Bl: Bit,] UInt<Ul, Bl>; [Ur: Unsigned, Br: Bit,] NInt<UInt<Ur, Br>>);
+
+use crate::Compare;
+
+macro_rules! impl_uint_int_cmp {
+    ([$($gl:tt)*] $Lhs:ty; [$($gr:tt)*] $Rhs:ty) => {
+        impl<$($gl)* $($gr)*> Cmp<$Rhs> for $Lhs
+        where
+            $Lhs: PrivateToSigned,
+            PrivateToSignedOut<$Lhs>: Cmp<$Rhs>,
+        {
+            type Output = Compare<PrivateToSignedOut<$Lhs>, $Rhs>;
+            #[inline]
+            fn compare<IM: InternalMarker>(&self, rhs: &$Rhs) -> Self::Output {
+                self.private_to_signed().compare::<IM>(rhs)
+            }
+        }
+
+        impl<$($gl)* $($gr)*> Min<$Rhs> for $Lhs
+        where
+            $Lhs: PrivateToSigned,
+            PrivateToSignedOut<$Lhs>: Min<$Rhs>,
+        {
+            type Output = Minimum<PrivateToSignedOut<$Lhs>, $Rhs>;
+            #[inline]
+            fn min(self, rhs: $Rhs) -> Self::Output {
+                Min::min(self.private_to_signed(), rhs)
+            }
+        }
+
+        impl<$($gl)* $($gr)*> Max<$Rhs> for $Lhs
+        where
+            $Lhs: PrivateToSigned,
+            PrivateToSignedOut<$Lhs>: Max<$Rhs>,
+        {
+            type Output = Maximum<PrivateToSignedOut<$Lhs>, $Rhs>;
+            #[inline]
+            fn max(self, rhs: $Rhs) -> Self::Output {
+                Max::max(self.private_to_signed(), rhs)
+            }
+        }
+    };
+}
+
+macro_rules! impl_int_uint_cmp {
+    ([$($gl:tt)*] $Lhs:ty; [$($gr:tt)*] $Rhs:ty) => {
+        impl<$($gl)* $($gr)*> Cmp<$Rhs> for $Lhs
+        where
+            $Rhs: PrivateToSigned,
+            $Lhs: Cmp<PrivateToSignedOut<$Rhs>>,
+        {
+            type Output = Compare<$Lhs, PrivateToSignedOut<$Rhs>>;
+            #[inline]
+            fn compare<IM: InternalMarker>(&self, rhs: &$Rhs) -> Self::Output {
+                self.compare::<IM>(&rhs.private_to_signed())
+            }
+        }
+
+        impl<$($gl)* $($gr)*> Min<$Rhs> for $Lhs
+        where
+            $Rhs: PrivateToSigned,
+            $Lhs: Min<PrivateToSignedOut<$Rhs>>,
+        {
+            type Output = Minimum<$Lhs, PrivateToSignedOut<$Rhs>>;
+            #[inline]
+            fn min(self, rhs: $Rhs) -> Self::Output {
+                Min::min(self, rhs.private_to_signed())
+            }
+        }
+
+        impl<$($gl)* $($gr)*> Max<$Rhs> for $Lhs
+        where
+            $Rhs: PrivateToSigned,
+            $Lhs: Max<PrivateToSignedOut<$Rhs>>,
+        {
+            type Output = Maximum<$Lhs, PrivateToSignedOut<$Rhs>>;
+            #[inline]
+            fn max(self, rhs: $Rhs) -> Self::Output {
+                Max::max(self, rhs.private_to_signed())
+            }
+        }
+    };
+}
+
+impl_uint_int_cmp!([] UTerm; [] Z0);
+impl_uint_int_cmp!([] UTerm; [Ur: Unsigned, Br: Bit,] PInt<UInt<Ur, Br>>);
+impl_uint_int_cmp!([] UTerm; [Ur: Unsigned, Br: Bit,] NInt<UInt<Ur, Br>>);
+impl_uint_int_cmp!([Ul: Unsigned, Bl: Bit,] UInt<Ul, Bl>; [] Z0);
+impl_uint_int_cmp!([Ul: Unsigned, Bl: Bit,] UInt<Ul, Bl>; [Ur: Unsigned, Br: Bit,] PInt<UInt<Ur, Br>>);
+impl_uint_int_cmp!([Ul: Unsigned, Bl: Bit,] UInt<Ul, Bl>; [Ur: Unsigned, Br: Bit,] NInt<UInt<Ur, Br>>);
+impl_int_uint_cmp!([] Z0; [] UTerm);
+impl_int_uint_cmp!([Pl: Unsigned, Pb: Bit,] PInt<UInt<Pl, Pb>>; [] UTerm);
+impl_int_uint_cmp!([Pl: Unsigned, Pb: Bit,] NInt<UInt<Pl, Pb>>; [] UTerm);
+impl_int_uint_cmp!([] Z0; [Ur: Unsigned, Br: Bit,] UInt<Ur, Br>);
+impl_int_uint_cmp!([Pl: Unsigned, Pb: Bit,] PInt<UInt<Pl, Pb>>; [Ur: Unsigned, Br: Bit,] UInt<Ur, Br>);
+impl_int_uint_cmp!([Pl: Unsigned, Pb: Bit,] NInt<UInt<Pl, Pb>>; [Ur: Unsigned, Br: Bit,] UInt<Ur, Br>);
+
+// ---------------------------------------------------------------------------------------
+// Two's complement encoding
+//
+// A `W`-bit two's complement number represents the integers in `-2^(W-1) .. 2^(W-1)`, where a
+// negative number `-n` is encoded as `2^W - n`. `Half<W>` is `2^(W-1)`, the value of the top bit.
+use crate::{
+    private::{PrivateFromTwosComplement, PrivateFromTwosComplementOut},
+    FromTwosComplement, IsGreaterOrEqual, IsLess, ToTwosComplement, True,
+};
+
+type Half<W> = Shright<Shleft<U1, W>, B1>;
+
+impl<W: Unsigned + NonZero> ToTwosComplement<W> for Z0 {
+    type Output = U0;
+}
+
+/// `P < 2^(W-1)` is encoded as itself
+impl<U, W> ToTwosComplement<W> for PInt<U>
+where
+    U: Unsigned + NonZero,
+    W: Unsigned + NonZero,
+    U1: Shl<W>,
+    Shleft<U1, W>: Shr<B1>,
+    Half<W>: Unsigned + NonZero,
+    PInt<U>: IsLess<PInt<Half<W>>, Output = True>,
+{
+    type Output = U;
+}
+
+/// `-N >= -2^(W-1)` is encoded as `2^W - N`
+impl<U, W> ToTwosComplement<W> for NInt<U>
+where
+    U: Unsigned + NonZero,
+    W: Unsigned + NonZero,
+    U1: Shl<W>,
+    Shleft<U1, W>: Shr<B1>,
+    Half<W>: Unsigned + NonZero,
+    NInt<U>: IsGreaterOrEqual<NInt<Half<W>>, Output = True>,
+    Shleft<U1, W>: Sub<U>,
+{
+    type Output = Diff<Shleft<U1, W>, U>;
+}
+
+/// `X < 2^W` is decoded as negative if its top bit, `2^(W-1)`, is set
+impl<X, W> FromTwosComplement<W> for X
+where
+    W: Unsigned + NonZero,
+    U1: Shl<W>,
+    Shleft<U1, W>: Shr<B1>,
+    Half<W>: Unsigned,
+    X: Unsigned + Cmp<Half<W>> + PrivateFromTwosComplement<W, Compare<X, Half<W>>>,
+    X: IsLess<Shleft<U1, W>, Output = True>,
+{
+    type Output = PrivateFromTwosComplementOut<X, W, Compare<X, Half<W>>>;
+}
+
+impl<X, W> PrivateFromTwosComplement<W, Less> for X
+where
+    X: PrivateToSigned,
+{
+    type Output = PrivateToSignedOut<X>;
+}
+
+impl<X, W> PrivateFromTwosComplement<W, Equal> for X
+where
+    U1: Shl<W>,
+    Shleft<U1, W>: Sub<X>,
+    Diff<Shleft<U1, W>, X>: Unsigned + NonZero,
+{
+    type Output = NInt<Diff<Shleft<U1, W>, X>>;
+}
+
+impl<X, W> PrivateFromTwosComplement<W, Greater> for X
+where
+    U1: Shl<W>,
+    Shleft<U1, W>: Sub<X>,
+    Diff<Shleft<U1, W>, X>: Unsigned + NonZero,
+{
+    type Output = NInt<Diff<Shleft<U1, W>, X>>;
+}
+
+// ---------------------------------------------------------------------------------------
+// BitAnd
+
+/// `Z0 & I = Z0`
+impl<I: Integer> BitAnd<I> for Z0 {
+    type Output = Z0;
+    #[inline]
+    fn bitand(self, _: I) -> Self::Output {
+        Z0
+    }
+}
+
+/// `PInt & Z0 = Z0`
+impl<U: Unsigned + NonZero> BitAnd<Z0> for PInt<U> {
+    type Output = Z0;
+    #[inline]
+    fn bitand(self, _: Z0) -> Self::Output {
+        Z0
+    }
+}
+
+/// `NInt & Z0 = Z0`
+impl<U: Unsigned + NonZero> BitAnd<Z0> for NInt<U> {
+    type Output = Z0;
+    #[inline]
+    fn bitand(self, _: Z0) -> Self::Output {
+        Z0
+    }
+}
+
+/// `P(Ul) & P(Ur) = Ul & Ur`
+impl<Ul, Ur> BitAnd<PInt<Ur>> for PInt<Ul>
+where
+    Ul: Unsigned + NonZero + BitAnd<Ur>,
+    Ur: Unsigned + NonZero,
+    And<Ul, Ur>: PrivateToSigned,
+{
+    type Output = PrivateToSignedOut<And<Ul, Ur>>;
+    #[inline]
+    fn bitand(self, rhs: PInt<Ur>) -> Self::Output {
+        (self.n & rhs.n).private_to_signed()
+    }
+}
+
+/// `P(Ul) & N(Ur) = Ul & !(Ur - 1) = Ul ^ (Ul & (Ur - 1))`
+impl<Ul, Ur> BitAnd<NInt<Ur>> for PInt<Ul>
+where
+    Ul: Unsigned + NonZero + BitAnd<Sub1<Ur>> + BitXor<And<Ul, Sub1<Ur>>>,
+    Ur: Unsigned + NonZero + Sub<B1>,
+    Xor<Ul, And<Ul, Sub1<Ur>>>: PrivateToSigned,
+{
+    type Output = PrivateToSignedOut<Xor<Ul, And<Ul, Sub1<Ur>>>>;
+    #[inline]
+    fn bitand(self, rhs: NInt<Ur>) -> Self::Output {
+        (self.n ^ (self.n & (rhs.n - B1))).private_to_signed()
+    }
+}
+
+/// `N(Ul) & P(Ur) = !(Ul - 1) & Ur = Ur ^ (Ur & (Ul - 1))`
+impl<Ul, Ur> BitAnd<PInt<Ur>> for NInt<Ul>
+where
+    Ul: Unsigned + NonZero + Sub<B1>,
+    Ur: Unsigned + NonZero + BitAnd<Sub1<Ul>> + BitXor<And<Ur, Sub1<Ul>>>,
+    Xor<Ur, And<Ur, Sub1<Ul>>>: PrivateToSigned,
+{
+    type Output = PrivateToSignedOut<Xor<Ur, And<Ur, Sub1<Ul>>>>;
+    #[inline]
+    fn bitand(self, rhs: PInt<Ur>) -> Self::Output {
+        (rhs.n ^ (rhs.n & (self.n - B1))).private_to_signed()
+    }
+}
+
+/// `N(Ul) & N(Ur) = !(Ul - 1) & !(Ur - 1) = !((Ul - 1) | (Ur - 1))`
+impl<Ul, Ur> BitAnd<NInt<Ur>> for NInt<Ul>
+where
+    Ul: Unsigned + NonZero + Sub<B1>,
+    Ur: Unsigned + NonZero + Sub<B1>,
+    Sub1<Ul>: BitOr<Sub1<Ur>>,
+    Or<Sub1<Ul>, Sub1<Ur>>: Add<B1>,
+    Add1<Or<Sub1<Ul>, Sub1<Ur>>>: Unsigned + NonZero,
+{
+    type Output = NInt<Add1<Or<Sub1<Ul>, Sub1<Ur>>>>;
+    #[inline]
+    fn bitand(self, rhs: NInt<Ur>) -> Self::Output {
+        NInt {
+            n: ((self.n - B1) | (rhs.n - B1)) + B1,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// BitOr
+
+/// `Z0 | I = I`
+impl<I: Integer> BitOr<I> for Z0 {
+    type Output = I;
+    #[inline]
+    fn bitor(self, rhs: I) -> Self::Output {
+        rhs
+    }
+}
+
+/// `PInt | Z0 = PInt`
+impl<U: Unsigned + NonZero> BitOr<Z0> for PInt<U> {
+    type Output = PInt<U>;
+    #[inline]
+    fn bitor(self, _: Z0) -> Self::Output {
+        self
+    }
+}
+
+/// `NInt | Z0 = NInt`
+impl<U: Unsigned + NonZero> BitOr<Z0> for NInt<U> {
+    type Output = NInt<U>;
+    #[inline]
+    fn bitor(self, _: Z0) -> Self::Output {
+        self
+    }
+}
+
+/// `P(Ul) | P(Ur) = P(Ul | Ur)`
+impl<Ul, Ur> BitOr<PInt<Ur>> for PInt<Ul>
+where
+    Ul: Unsigned + NonZero + BitOr<Ur>,
+    Ur: Unsigned + NonZero,
+    Or<Ul, Ur>: Unsigned + NonZero,
+{
+    type Output = PInt<Or<Ul, Ur>>;
+    #[inline]
+    fn bitor(self, rhs: PInt<Ur>) -> Self::Output {
+        PInt { n: self.n | rhs.n }
+    }
+}
+
+/// `P(Ul) | N(Ur) = Ul | !(Ur - 1) = !((Ur - 1) ^ ((Ur - 1) & Ul))`
+impl<Ul, Ur> BitOr<NInt<Ur>> for PInt<Ul>
+where
+    Ul: Unsigned + NonZero,
+    Ur: Unsigned + NonZero + Sub<B1>,
+    Sub1<Ur>: BitAnd<Ul> + BitXor<And<Sub1<Ur>, Ul>>,
+    Xor<Sub1<Ur>, And<Sub1<Ur>, Ul>>: Add<B1>,
+    Add1<Xor<Sub1<Ur>, And<Sub1<Ur>, Ul>>>: Unsigned + NonZero,
+{
+    type Output = NInt<Add1<Xor<Sub1<Ur>, And<Sub1<Ur>, Ul>>>>;
+    #[inline]
+    fn bitor(self, rhs: NInt<Ur>) -> Self::Output {
+        NInt {
+            n: ((rhs.n - B1) ^ ((rhs.n - B1) & self.n)) + B1,
+        }
+    }
+}
+
+/// `N(Ul) | P(Ur) = !(Ul - 1) | Ur = !((Ul - 1) ^ ((Ul - 1) & Ur))`
+impl<Ul, Ur> BitOr<PInt<Ur>> for NInt<Ul>
+where
+    Ul: Unsigned + NonZero + Sub<B1>,
+    Ur: Unsigned + NonZero,
+    Sub1<Ul>: BitAnd<Ur> + BitXor<And<Sub1<Ul>, Ur>>,
+    Xor<Sub1<Ul>, And<Sub1<Ul>, Ur>>: Add<B1>,
+    Add1<Xor<Sub1<Ul>, And<Sub1<Ul>, Ur>>>: Unsigned + NonZero,
+{
+    type Output = NInt<Add1<Xor<Sub1<Ul>, And<Sub1<Ul>, Ur>>>>;
+    #[inline]
+    fn bitor(self, rhs: PInt<Ur>) -> Self::Output {
+        NInt {
+            n: ((self.n - B1) ^ ((self.n - B1) & rhs.n)) + B1,
+        }
+    }
+}
+
+/// `N(Ul) | N(Ur) = !(Ul - 1) | !(Ur - 1) = !((Ul - 1) & (Ur - 1))`
+impl<Ul, Ur> BitOr<NInt<Ur>> for NInt<Ul>
+where
+    Ul: Unsigned + NonZero + Sub<B1>,
+    Ur: Unsigned + NonZero + Sub<B1>,
+    Sub1<Ul>: BitAnd<Sub1<Ur>>,
+    And<Sub1<Ul>, Sub1<Ur>>: Add<B1>,
+    Add1<And<Sub1<Ul>, Sub1<Ur>>>: Unsigned + NonZero,
+{
+    type Output = NInt<Add1<And<Sub1<Ul>, Sub1<Ur>>>>;
+    #[inline]
+    fn bitor(self, rhs: NInt<Ur>) -> Self::Output {
+        NInt {
+            n: ((self.n - B1) & (rhs.n - B1)) + B1,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// BitXor
+
+/// `Z0 ^ I = I`
+impl<I: Integer> BitXor<I> for Z0 {
+    type Output = I;
+    #[inline]
+    fn bitxor(self, rhs: I) -> Self::Output {
+        rhs
+    }
+}
+
+/// `PInt ^ Z0 = PInt`
+impl<U: Unsigned + NonZero> BitXor<Z0> for PInt<U> {
+    type Output = PInt<U>;
+    #[inline]
+    fn bitxor(self, _: Z0) -> Self::Output {
+        self
+    }
+}
+
+/// `NInt ^ Z0 = NInt`
+impl<U: Unsigned + NonZero> BitXor<Z0> for NInt<U> {
+    type Output = NInt<U>;
+    #[inline]
+    fn bitxor(self, _: Z0) -> Self::Output {
+        self
+    }
+}
+
+/// `P(Ul) ^ P(Ur) = Ul ^ Ur`
+impl<Ul, Ur> BitXor<PInt<Ur>> for PInt<Ul>
+where
+    Ul: Unsigned + NonZero + BitXor<Ur>,
+    Ur: Unsigned + NonZero,
+    Xor<Ul, Ur>: PrivateToSigned,
+{
+    type Output = PrivateToSignedOut<Xor<Ul, Ur>>;
+    #[inline]
+    fn bitxor(self, rhs: PInt<Ur>) -> Self::Output {
+        (self.n ^ rhs.n).private_to_signed()
+    }
+}
+
+/// `P(Ul) ^ N(Ur) = Ul ^ !(Ur - 1) = !(Ul ^ (Ur - 1))`
+impl<Ul, Ur> BitXor<NInt<Ur>> for PInt<Ul>
+where
+    Ul: Unsigned + NonZero + BitXor<Sub1<Ur>>,
+    Ur: Unsigned + NonZero + Sub<B1>,
+    Xor<Ul, Sub1<Ur>>: Add<B1>,
+    Add1<Xor<Ul, Sub1<Ur>>>: Unsigned + NonZero,
+{
+    type Output = NInt<Add1<Xor<Ul, Sub1<Ur>>>>;
+    #[inline]
+    fn bitxor(self, rhs: NInt<Ur>) -> Self::Output {
+        NInt {
+            n: (self.n ^ (rhs.n - B1)) + B1,
+        }
+    }
+}
+
+/// `N(Ul) ^ P(Ur) = !(Ul - 1) ^ Ur = !((Ul - 1) ^ Ur)`
+impl<Ul, Ur> BitXor<PInt<Ur>> for NInt<Ul>
+where
+    Ul: Unsigned + NonZero + Sub<B1>,
+    Ur: Unsigned + NonZero,
+    Sub1<Ul>: BitXor<Ur>,
+    Xor<Sub1<Ul>, Ur>: Add<B1>,
+    Add1<Xor<Sub1<Ul>, Ur>>: Unsigned + NonZero,
+{
+    type Output = NInt<Add1<Xor<Sub1<Ul>, Ur>>>;
+    #[inline]
+    fn bitxor(self, rhs: PInt<Ur>) -> Self::Output {
+        NInt {
+            n: ((self.n - B1) ^ rhs.n) + B1,
+        }
+    }
+}
+
+/// `N(Ul) ^ N(Ur) = !(Ul - 1) ^ !(Ur - 1) = (Ul - 1) ^ (Ur - 1)`
+impl<Ul, Ur> BitXor<NInt<Ur>> for NInt<Ul>
+where
+    Ul: Unsigned + NonZero + Sub<B1>,
+    Ur: Unsigned + NonZero + Sub<B1>,
+    Sub1<Ul>: BitXor<Sub1<Ur>>,
+    Xor<Sub1<Ul>, Sub1<Ur>>: PrivateToSigned,
+{
+    type Output = PrivateToSignedOut<Xor<Sub1<Ul>, Sub1<Ur>>>;
+    #[inline]
+    fn bitxor(self, rhs: NInt<Ur>) -> Self::Output {
+        ((self.n - B1) ^ (rhs.n - B1)).private_to_signed()
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Not
+
+/// `!Z0 = N1`
+impl Not for Z0 {
+    type Output = N1;
+    #[inline]
+    fn not(self) -> Self::Output {
+        N1::new()
+    }
+}
+
+/// `!P(U) = N(U + 1)`
+impl<U> Not for PInt<U>
+where
+    U: Unsigned + NonZero + Add<B1>,
+    Add1<U>: Unsigned + NonZero,
+{
+    type Output = NInt<Add1<U>>;
+    #[inline]
+    fn not(self) -> Self::Output {
+        NInt { n: self.n + B1 }
+    }
+}
+
+/// `!N(U) = U - 1`
+impl<U> Not for NInt<U>
+where
+    U: Unsigned + NonZero + Sub<B1>,
+    Sub1<U>: PrivateToSigned,
+{
+    type Output = PrivateToSignedOut<Sub1<U>>;
+    #[inline]
+    fn not(self) -> Self::Output {
+        (self.n - B1).private_to_signed()
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Shl
+
+/// `Z0 << U = Z0`
+impl<U: Unsigned> Shl<U> for Z0 {
+    type Output = Z0;
+    #[inline]
+    fn shl(self, _: U) -> Self::Output {
+        Z0
+    }
+}
+
+/// `P(Ul) << Ur = P(Ul << Ur)`
+impl<Ul, Ur> Shl<Ur> for PInt<Ul>
+where
+    Ul: Unsigned + NonZero + Shl<Ur>,
+    Ur: Unsigned,
+    Shleft<Ul, Ur>: Unsigned + NonZero,
+{
+    type Output = PInt<Shleft<Ul, Ur>>;
+    #[inline]
+    fn shl(self, rhs: Ur) -> Self::Output {
+        PInt { n: self.n << rhs }
+    }
+}
+
+/// `N(Ul) << Ur = N(Ul << Ur)`
+impl<Ul, Ur> Shl<Ur> for NInt<Ul>
+where
+    Ul: Unsigned + NonZero + Shl<Ur>,
+    Ur: Unsigned,
+    Shleft<Ul, Ur>: Unsigned + NonZero,
+{
+    type Output = NInt<Shleft<Ul, Ur>>;
+    #[inline]
+    fn shl(self, rhs: Ur) -> Self::Output {
+        NInt { n: self.n << rhs }
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Shr
+
+/// `Z0 >> U = Z0`
+impl<U: Unsigned> Shr<U> for Z0 {
+    type Output = Z0;
+    #[inline]
+    fn shr(self, _: U) -> Self::Output {
+        Z0
+    }
+}
+
+/// `P(Ul) >> Ur = Ul >> Ur`
+impl<Ul, Ur> Shr<Ur> for PInt<Ul>
+where
+    Ul: Unsigned + NonZero + Shr<Ur>,
+    Ur: Unsigned,
+    Shright<Ul, Ur>: PrivateToSigned,
+{
+    type Output = PrivateToSignedOut<Shright<Ul, Ur>>;
+    #[inline]
+    fn shr(self, rhs: Ur) -> Self::Output {
+        (self.n >> rhs).private_to_signed()
+    }
+}
+
+/// `N(Ul) >> Ur = !((Ul - 1) >> Ur) = N(((Ul - 1) >> Ur) + 1)`
+///
+/// This is an arithmetic shift, so it rounds toward negative infinity.
+impl<Ul, Ur> Shr<Ur> for NInt<Ul>
+where
+    Ul: Unsigned + NonZero + Sub<B1>,
+    Ur: Unsigned,
+    Sub1<Ul>: Shr<Ur>,
+    Shright<Sub1<Ul>, Ur>: Add<B1>,
+    Add1<Shright<Sub1<Ul>, Ur>>: Unsigned + NonZero,
+{
+    type Output = NInt<Add1<Shright<Sub1<Ul>, Ur>>>;
+    #[inline]
+    fn shr(self, rhs: Ur) -> Self::Output {
+        NInt {
+            n: ((self.n - B1) >> rhs) + B1,
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------------------
 // Pow
 
@@ -960,6 +1770,137 @@ where
     type Output = PInt<Gcf<U1, U2>>;
 }
 
+// ---------------------------------------------------------------------------------------
+// Lcm
+use crate::{Lcm, Lcmf};
+
+impl Lcm<Z0> for Z0 {
+    type Output = Z0;
+}
+
+impl<U> Lcm<PInt<U>> for Z0
+where
+    U: Unsigned + NonZero,
+{
+    type Output = Z0;
+}
+
+impl<U> Lcm<Z0> for PInt<U>
+where
+    U: Unsigned + NonZero,
+{
+    type Output = Z0;
+}
+
+impl<U> Lcm<NInt<U>> for Z0
+where
+    U: Unsigned + NonZero,
+{
+    type Output = Z0;
+}
+
+impl<U> Lcm<Z0> for NInt<U>
+where
+    U: Unsigned + NonZero,
+{
+    type Output = Z0;
+}
+
+impl<U1, U2> Lcm<PInt<U2>> for PInt<U1>
+where
+    U1: Unsigned + NonZero + Lcm<U2>,
+    U2: Unsigned + NonZero,
+    Lcmf<U1, U2>: Unsigned + NonZero,
+{
+    type Output = PInt<Lcmf<U1, U2>>;
+}
+
+impl<U1, U2> Lcm<PInt<U2>> for NInt<U1>
+where
+    U1: Unsigned + NonZero + Lcm<U2>,
+    U2: Unsigned + NonZero,
+    Lcmf<U1, U2>: Unsigned + NonZero,
+{
+    type Output = PInt<Lcmf<U1, U2>>;
+}
+
+impl<U1, U2> Lcm<NInt<U2>> for PInt<U1>
+where
+    U1: Unsigned + NonZero + Lcm<U2>,
+    U2: Unsigned + NonZero,
+    Lcmf<U1, U2>: Unsigned + NonZero,
+{
+    type Output = PInt<Lcmf<U1, U2>>;
+}
+
+impl<U1, U2> Lcm<NInt<U2>> for NInt<U1>
+where
+    U1: Unsigned + NonZero + Lcm<U2>,
+    U2: Unsigned + NonZero,
+    Lcmf<U1, U2>: Unsigned + NonZero,
+{
+    type Output = PInt<Lcmf<U1, U2>>;
+}
+
+// ---------------------------------------------------------------------------------------
+// ExtendedGcd
+use crate::{
+    array::{ATerm, TArr},
+    private::{PrivateExtendedGcd, PrivateExtendedGcdOut},
+    ExtGcd, ExtendedGcd, Prod,
+};
+
+/// `gcd(0, 0) = 0 * 0 + 0 * 0`
+impl ExtendedGcd<Z0> for Z0 {
+    type Output = TArr<Z0, TArr<Z0, TArr<Z0, ATerm>>>;
+}
+
+/// `gcd(P, 0) = P * 1 + 0 * 0`
+impl<U> ExtendedGcd<Z0> for PInt<U>
+where
+    U: Unsigned + NonZero,
+{
+    type Output = TArr<PInt<U>, TArr<P1, TArr<Z0, ATerm>>>;
+}
+
+/// `gcd(-P, 0) = -P * -1 + 0 * 0`
+impl<U> ExtendedGcd<Z0> for NInt<U>
+where
+    U: Unsigned + NonZero,
+{
+    type Output = TArr<PInt<U>, TArr<N1, TArr<Z0, ATerm>>>;
+}
+
+/// `gcd(A, B) = gcd(B, A % B)`
+impl<I, U> ExtendedGcd<PInt<U>> for I
+where
+    I: Integer + Rem<PInt<U>> + Div<PInt<U>>,
+    U: Unsigned + NonZero,
+    PInt<U>: ExtendedGcd<Mod<I, PInt<U>>>,
+    ExtGcd<PInt<U>, Mod<I, PInt<U>>>: PrivateExtendedGcd<Quot<I, PInt<U>>>,
+{
+    type Output = PrivateExtendedGcdOut<ExtGcd<PInt<U>, Mod<I, PInt<U>>>, Quot<I, PInt<U>>>;
+}
+
+/// `gcd(A, B) = gcd(B, A % B)`
+impl<I, U> ExtendedGcd<NInt<U>> for I
+where
+    I: Integer + Rem<NInt<U>> + Div<NInt<U>>,
+    U: Unsigned + NonZero,
+    NInt<U>: ExtendedGcd<Mod<I, NInt<U>>>,
+    ExtGcd<NInt<U>, Mod<I, NInt<U>>>: PrivateExtendedGcd<Quot<I, NInt<U>>>,
+{
+    type Output = PrivateExtendedGcdOut<ExtGcd<NInt<U>, Mod<I, NInt<U>>>, Quot<I, NInt<U>>>;
+}
+
+impl<G, X, Y, Q> PrivateExtendedGcd<Q> for TArr<G, TArr<X, TArr<Y, ATerm>>>
+where
+    Q: Mul<Y>,
+    X: Sub<Prod<Q, Y>>,
+{
+    type Output = TArr<G, TArr<Y, TArr<Diff<X, Prod<Q, Y>>, ATerm>>>;
+}
+
 // ---------------------------------------------------------------------------------------
 // Min
 use crate::{Max, Maximum, Min, Minimum};
@@ -1179,6 +2120,187 @@ where
     }
 }
 
+// ---------------------------------------------------------------------------------------
+// AbsDiff
+use crate::{Abs, AbsDiff, AbsVal, Diff};
+
+impl<I> AbsDiff<I> for Z0
+where
+    I: Integer,
+    Z0: Sub<I>,
+    Diff<Z0, I>: Abs,
+{
+    type Output = AbsVal<Diff<Z0, I>>;
+}
+
+impl<U, I> AbsDiff<I> for PInt<U>
+where
+    U: Unsigned + NonZero,
+    I: Integer,
+    PInt<U>: Sub<I>,
+    Diff<PInt<U>, I>: Abs,
+{
+    type Output = AbsVal<Diff<PInt<U>, I>>;
+}
+
+impl<U, I> AbsDiff<I> for NInt<U>
+where
+    U: Unsigned + NonZero,
+    I: Integer,
+    NInt<U>: Sub<I>,
+    Diff<NInt<U>, I>: Abs,
+{
+    type Output = AbsVal<Diff<NInt<U>, I>>;
+}
+
+// ---------------------------------------------------------------------------------------
+// SquareRoot and Logarithm2
+use crate::{
+    private::{PrivateLogarithm2, PrivateSquareRoot},
+    Log2, Logarithm2, Sqrt, SquareRoot,
+};
+
+// sqrt(0) = 0.
+impl PrivateSquareRoot for Z0 {
+    type Output = Z0;
+}
+
+impl<U> PrivateSquareRoot for PInt<U>
+where
+    U: Unsigned + NonZero + SquareRoot,
+    Sqrt<U>: Unsigned + NonZero,
+{
+    type Output = PInt<Sqrt<U>>;
+}
+
+// log2(P1) = Z0, so the result is converted back from an unsigned integer.
+impl<U> PrivateLogarithm2 for PInt<U>
+where
+    U: Unsigned + NonZero + Logarithm2,
+    Log2<U>: PrivateToSigned,
+{
+    type Output = PrivateToSignedOut<Log2<U>>;
+}
+
+// ---------------------------------------------------------------------------------------
+// Sign and parity predicates
+use crate::{IsEven, IsNegative, IsOdd, IsPositive, IsZero, Signum};
+
+impl Signum for Z0 {
+    type Output = Z0;
+}
+
+impl<U: Unsigned + NonZero> Signum for PInt<U> {
+    type Output = P1;
+}
+
+impl<U: Unsigned + NonZero> Signum for NInt<U> {
+    type Output = N1;
+}
+
+impl IsNegative for Z0 {
+    type Output = B0;
+}
+
+impl<U: Unsigned + NonZero> IsNegative for PInt<U> {
+    type Output = B0;
+}
+
+impl<U: Unsigned + NonZero> IsNegative for NInt<U> {
+    type Output = B1;
+}
+
+impl IsPositive for Z0 {
+    type Output = B0;
+}
+
+impl<U: Unsigned + NonZero> IsPositive for PInt<U> {
+    type Output = B1;
+}
+
+impl<U: Unsigned + NonZero> IsPositive for NInt<U> {
+    type Output = B0;
+}
+
+impl IsZero for Z0 {
+    type Output = B1;
+}
+
+impl<U: Unsigned + NonZero> IsZero for PInt<U> {
+    type Output = B0;
+}
+
+impl<U: Unsigned + NonZero> IsZero for NInt<U> {
+    type Output = B0;
+}
+
+impl IsEven for Z0 {
+    type Output = B1;
+}
+
+impl<U: Unsigned + NonZero + IsEven> IsEven for PInt<U> {
+    type Output = <U as IsEven>::Output;
+}
+
+impl<U: Unsigned + NonZero + IsEven> IsEven for NInt<U> {
+    type Output = <U as IsEven>::Output;
+}
+
+impl IsOdd for Z0 {
+    type Output = B0;
+}
+
+impl<U: Unsigned + NonZero + IsOdd> IsOdd for PInt<U> {
+    type Output = <U as IsOdd>::Output;
+}
+
+impl<U: Unsigned + NonZero + IsOdd> IsOdd for NInt<U> {
+    type Output = <U as IsOdd>::Output;
+}
+
+// ---------------------------------------------------------------------------------------
+// Bit counting
+//
+// Only non-negative integers are supported, as negative ones would have infinitely many ones
+// in two's complement.
+use crate::{CountOnes, CountZeros, LeadingZeros, Parity, TrailingZeros};
+
+impl CountOnes for Z0 {
+    type Output = U0;
+}
+
+impl<U: Unsigned + NonZero + CountOnes> CountOnes for PInt<U> {
+    type Output = <U as CountOnes>::Output;
+}
+
+impl<W: Unsigned> CountZeros<W> for Z0 {
+    type Output = W;
+}
+
+impl<U: Unsigned + NonZero + CountZeros<W>, W> CountZeros<W> for PInt<U> {
+    type Output = <U as CountZeros<W>>::Output;
+}
+
+impl<U: Unsigned + NonZero + TrailingZeros> TrailingZeros for PInt<U> {
+    type Output = <U as TrailingZeros>::Output;
+}
+
+impl<W: Unsigned> LeadingZeros<W> for Z0 {
+    type Output = W;
+}
+
+impl<U: Unsigned + NonZero + LeadingZeros<W>, W> LeadingZeros<W> for PInt<U> {
+    type Output = <U as LeadingZeros<W>>::Output;
+}
+
+impl Parity for Z0 {
+    type Output = B0;
+}
+
+impl<U: Unsigned + NonZero + Parity> Parity for PInt<U> {
+    type Output = <U as Parity>::Output;
+}
+
 // -----------------------------------------
 // ToInt
 
@@ -1308,7 +2430,12 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::{consts::*, Integer, ToInt};
+    use crate::{
+        assert_type_eq, consts::*, tarr, AsSigned, AsUnsigned, Bit, Clamped, Compare, Diff, Equal,
+        ExtGcd, FromTwosComp, Greater, Integer, IsEvenOut, IsNegativeOut, IsOddOut, IsPositiveOut,
+        IsZeroOut, Le, Less, Log2, Maximum, Minimum, Mod, Negate, PartialQuot, Prod, Quot, Sgn,
+        Sqrt, Sum, ToInt, TwosComp,
+    };
 
     #[test]
     fn to_ix_min() {
@@ -1316,6 +2443,133 @@ mod tests {
         assert_eq!(N32768::to_i16(), ::core::i16::MIN);
     }
 
+    #[test]
+    fn bit_count() {
+        use crate::{
+            assert_type_eq, NumLeadingZeros, NumOnes, NumTrailingZeros, NumZeros, ParityBit,
+        };
+
+        assert_type_eq!(NumOnes<Z0>, U0);
+        assert_type_eq!(NumOnes<P7>, U3);
+        assert_type_eq!(NumZeros<Z0, U4>, U4);
+        assert_type_eq!(NumZeros<P5, U4>, U2);
+        assert_type_eq!(NumTrailingZeros<P12>, U2);
+        assert_type_eq!(NumLeadingZeros<Z0, U8>, U8);
+        assert_type_eq!(NumLeadingZeros<P12, U8>, U4);
+        assert_type_eq!(ParityBit<Z0>, B0);
+        assert_type_eq!(ParityBit<P7>, B1);
+    }
+
+    #[test]
+    fn extended_gcd() {
+        assert_type_eq!(ExtGcd<Z0, Z0>, tarr![Z0, Z0, Z0]);
+        assert_type_eq!(ExtGcd<P5, Z0>, tarr![P5, P1, Z0]);
+        assert_type_eq!(ExtGcd<N5, Z0>, tarr![P5, N1, Z0]);
+        assert_type_eq!(ExtGcd<Z0, P5>, tarr![P5, Z0, P1]);
+        assert_type_eq!(ExtGcd<P12, P8>, tarr![P4, P1, N1]);
+        assert_type_eq!(ExtGcd<N12, P8>, tarr![P4, N1, N1]);
+        assert_type_eq!(ExtGcd<P3, P7>, tarr![P1, N2, P1]);
+        assert_type_eq!(ExtGcd<P7, N3>, tarr![P1, P1, P2]);
+        assert_type_eq!(ExtGcd<P240, P46>, tarr![P2, N9, P47]);
+    }
+
+    #[test]
+    fn predicates() {
+        assert_type_eq!(Sgn<N5>, N1);
+        assert_type_eq!(Sgn<Z0>, Z0);
+        assert_type_eq!(Sgn<P5>, P1);
+        assert!(<IsNegativeOut<N5>>::to_bool());
+        assert!(!<IsNegativeOut<Z0>>::to_bool());
+        assert!(!<IsPositiveOut<N5>>::to_bool());
+        assert!(<IsPositiveOut<P5>>::to_bool());
+        assert!(<IsZeroOut<Z0>>::to_bool());
+        assert!(!<IsZeroOut<N1>>::to_bool());
+        assert!(<IsEvenOut<N6>>::to_bool());
+        assert!(<IsEvenOut<Z0>>::to_bool());
+        assert!(!<IsEvenOut<P3>>::to_bool());
+        assert!(<IsOddOut<N3>>::to_bool());
+        assert!(!<IsOddOut<P4>>::to_bool());
+    }
+
+    #[test]
+    fn sqrt_log2() {
+        assert_type_eq!(Sqrt<Z0>, Z0);
+        assert_type_eq!(Sqrt<P1>, P1);
+        assert_type_eq!(Sqrt<P15>, P3);
+        assert_type_eq!(Sqrt<P16>, P4);
+        assert_type_eq!(Log2<P1>, Z0);
+        assert_type_eq!(Log2<P7>, P2);
+        assert_type_eq!(Log2<P8>, P3);
+    }
+
+    #[test]
+    fn conversions() {
+        assert_type_eq!(AsSigned<U0>, Z0);
+        assert_type_eq!(AsSigned<U5>, P5);
+        assert_type_eq!(AsUnsigned<Z0>, U0);
+        assert_type_eq!(AsUnsigned<P5>, U5);
+        assert_type_eq!(AsUnsigned<AsSigned<U1024>>, U1024);
+    }
+
+    #[test]
+    fn mixed_signedness() {
+        assert_type_eq!(Negate<U0>, Z0);
+        assert_type_eq!(Negate<U5>, N5);
+        assert_type_eq!(Sum<U5, P3>, P8);
+        assert_type_eq!(Sum<N3, U2>, N1);
+        assert_type_eq!(Diff<U2, P5>, N3);
+        assert_type_eq!(Diff<Z0, U4>, N4);
+        assert_type_eq!(Prod<U3, N2>, N6);
+        assert_type_eq!(Prod<P2, U0>, Z0);
+        assert_type_eq!(Compare<U5, N2>, Greater);
+        assert_type_eq!(Compare<U0, Z0>, Equal);
+        assert_type_eq!(Compare<N2, U0>, Less);
+        assert_type_eq!(Compare<P3, U4>, Less);
+        assert_type_eq!(Le<P3, U4>, True);
+        assert_type_eq!(Minimum<U5, N2>, N2);
+        assert_type_eq!(Minimum<Z0, U3>, Z0);
+        assert_type_eq!(Maximum<U0, N7>, Z0);
+        assert_type_eq!(Maximum<P3, U4>, P4);
+        assert_type_eq!(Quot<U7, N2>, N3);
+        assert_type_eq!(Quot<N9, U3>, N3);
+        assert_type_eq!(Mod<U7, N2>, P1);
+        assert_type_eq!(Mod<N7, U2>, N1);
+        assert_type_eq!(PartialQuot<U8, N4>, N2);
+        assert_type_eq!(PartialQuot<P9, U3>, P3);
+    }
+
+    #[test]
+    fn clamp() {
+        assert_type_eq!(Clamped<N7, N3, P5>, N3);
+        assert_type_eq!(Clamped<N3, N3, P5>, N3);
+        assert_type_eq!(Clamped<Z0, N3, P5>, Z0);
+        assert_type_eq!(Clamped<P5, N3, P5>, P5);
+        assert_type_eq!(Clamped<P8, N3, P5>, P5);
+        assert_type_eq!(Clamped<N9, N6, N2>, N6);
+        assert_type_eq!(Clamped<P1, N6, N2>, N2);
+        assert_type_eq!(Clamped<N4, P2, P2>, P2);
+        assert_type_eq!(Clamped<P2, P2, P2>, P2);
+        assert_type_eq!(Clamped<P4, Z0, Z0>, Z0);
+    }
+
+    #[test]
+    fn twos_complement() {
+        assert_type_eq!(TwosComp<Z0, U1>, U0);
+        assert_type_eq!(TwosComp<N1, U1>, U1);
+        assert_type_eq!(TwosComp<P5, U4>, U5);
+        assert_type_eq!(TwosComp<P7, U4>, U7);
+        assert_type_eq!(TwosComp<N8, U4>, U8);
+        assert_type_eq!(TwosComp<N3, U4>, U13);
+        assert_type_eq!(TwosComp<N1, U10>, U1023);
+        assert_type_eq!(FromTwosComp<U0, U1>, Z0);
+        assert_type_eq!(FromTwosComp<U1, U1>, N1);
+        assert_type_eq!(FromTwosComp<U7, U4>, P7);
+        assert_type_eq!(FromTwosComp<U8, U4>, N8);
+        assert_type_eq!(FromTwosComp<U13, U4>, N3);
+        assert_type_eq!(FromTwosComp<U15, U4>, N1);
+        assert_type_eq!(FromTwosComp<TwosComp<N100, U8>, U8>, N100);
+    }
+
     #[test]
     fn int_toint_test() {
         // i8
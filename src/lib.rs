@@ -88,10 +88,12 @@ pub mod int;
 pub mod marker_traits;
 pub mod operator_aliases;
 pub mod private;
+pub mod rational;
 pub mod type_operators;
 pub mod uint;
 
 pub mod array;
+pub mod option;
 
 pub use crate::{
     array::{ATerm, TArr},
@@ -99,6 +101,8 @@ pub use crate::{
     int::{NInt, PInt},
     marker_traits::*,
     operator_aliases::*,
+    option::{TNone, TSome},
+    rational::Ratio,
     type_operators::*,
     uint::{UInt, UTerm},
 };
@@ -160,6 +164,96 @@ impl Ord for Equal {
     }
 }
 
+impl OrdReverse for Less {
+    type Output = Greater;
+}
+impl OrdReverse for Equal {
+    type Output = Equal;
+}
+impl OrdReverse for Greater {
+    type Output = Less;
+}
+
+impl<Next> OrdThen<Next> for Less {
+    type Output = Less;
+}
+impl<Next> OrdThen<Next> for Equal {
+    type Output = Next;
+}
+impl<Next> OrdThen<Next> for Greater {
+    type Output = Greater;
+}
+
+impl OrdToInt for Less {
+    type Output = N1;
+}
+impl OrdToInt for Equal {
+    type Output = Z0;
+}
+impl OrdToInt for Greater {
+    type Output = P1;
+}
+
+impl OrdIsLess for Less {
+    type Output = B1;
+}
+impl OrdIsLess for Equal {
+    type Output = B0;
+}
+impl OrdIsLess for Greater {
+    type Output = B0;
+}
+
+impl OrdIsLessOrEqual for Less {
+    type Output = B1;
+}
+impl OrdIsLessOrEqual for Equal {
+    type Output = B1;
+}
+impl OrdIsLessOrEqual for Greater {
+    type Output = B0;
+}
+
+impl OrdIsEqual for Less {
+    type Output = B0;
+}
+impl OrdIsEqual for Equal {
+    type Output = B1;
+}
+impl OrdIsEqual for Greater {
+    type Output = B0;
+}
+
+impl OrdIsNotEqual for Less {
+    type Output = B1;
+}
+impl OrdIsNotEqual for Equal {
+    type Output = B0;
+}
+impl OrdIsNotEqual for Greater {
+    type Output = B1;
+}
+
+impl OrdIsGreater for Less {
+    type Output = B0;
+}
+impl OrdIsGreater for Equal {
+    type Output = B0;
+}
+impl OrdIsGreater for Greater {
+    type Output = B1;
+}
+
+impl OrdIsGreaterOrEqual for Less {
+    type Output = B0;
+}
+impl OrdIsGreaterOrEqual for Equal {
+    type Output = B1;
+}
+impl OrdIsGreaterOrEqual for Greater {
+    type Output = B1;
+}
+
 /// Asserts that two types are the same.
 #[macro_export]
 macro_rules! assert_type_eq {
@@ -180,8 +274,8 @@ macro_rules! assert_type {
 
 mod sealed {
     use crate::{
-        ATerm, Bit, Equal, Greater, Less, NInt, NonZero, PInt, TArr, UInt, UTerm, Unsigned, B0, B1,
-        Z0,
+        ATerm, Bit, Equal, Greater, Integer, Less, NInt, NonZero, PInt, Ratio, TArr, TNone, TSome,
+        UInt, UTerm, Unsigned, B0, B1, Z0,
     };
 
     pub trait Sealed {}
@@ -196,10 +290,53 @@ mod sealed {
     impl<U: Unsigned + NonZero> Sealed for PInt<U> {}
     impl<U: Unsigned + NonZero> Sealed for NInt<U> {}
 
+    impl<N: Integer, D: Unsigned + NonZero> Sealed for Ratio<N, D> {}
+
     impl Sealed for Less {}
     impl Sealed for Equal {}
     impl Sealed for Greater {}
 
     impl Sealed for ATerm {}
     impl<V, A> Sealed for TArr<V, A> {}
+
+    impl Sealed for TNone {}
+    impl<T> Sealed for TSome<T> {}
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        assert_type_eq, Equal, False, Greater, Less, OrdIsEqualOut, OrdIsGreaterOrEqualOut,
+        OrdIsGreaterOut, OrdIsLessOrEqualOut, OrdIsLessOut, OrdIsNotEqualOut, OrdReverseOut,
+        OrdThenOut, OrdToIntOut, True, N1, P1, Z0,
+    };
+
+    #[test]
+    fn ordering_ops() {
+        assert_type_eq!(OrdReverseOut<Less>, Greater);
+        assert_type_eq!(OrdReverseOut<Equal>, Equal);
+        assert_type_eq!(OrdReverseOut<Greater>, Less);
+
+        assert_type_eq!(OrdThenOut<Less, Greater>, Less);
+        assert_type_eq!(OrdThenOut<Equal, Greater>, Greater);
+        assert_type_eq!(OrdThenOut<Equal, Equal>, Equal);
+        assert_type_eq!(OrdThenOut<Greater, Less>, Greater);
+
+        assert_type_eq!(OrdToIntOut<Less>, N1);
+        assert_type_eq!(OrdToIntOut<Equal>, Z0);
+        assert_type_eq!(OrdToIntOut<Greater>, P1);
+
+        assert_type_eq!(OrdIsLessOut<Less>, True);
+        assert_type_eq!(OrdIsLessOut<Equal>, False);
+        assert_type_eq!(OrdIsLessOrEqualOut<Equal>, True);
+        assert_type_eq!(OrdIsLessOrEqualOut<Greater>, False);
+        assert_type_eq!(OrdIsEqualOut<Equal>, True);
+        assert_type_eq!(OrdIsEqualOut<Greater>, False);
+        assert_type_eq!(OrdIsNotEqualOut<Less>, True);
+        assert_type_eq!(OrdIsNotEqualOut<Equal>, False);
+        assert_type_eq!(OrdIsGreaterOut<Greater>, True);
+        assert_type_eq!(OrdIsGreaterOut<Equal>, False);
+        assert_type_eq!(OrdIsGreaterOrEqualOut<Equal>, True);
+        assert_type_eq!(OrdIsGreaterOrEqualOut<Less>, False);
+    }
 }
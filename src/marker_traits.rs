@@ -45,6 +45,17 @@ pub trait Bit: Sealed + Copy + Default + 'static {
     fn to_u8() -> u8;
     #[allow(missing_docs)]
     fn to_bool() -> bool;
+
+    /// Returns `then` if this bit is `B1`, and `otherwise` if it is `B0`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use typenum::{Bit, B0, B1};
+    ///
+    /// assert_eq!(B1::select("then", "otherwise"), "then");
+    /// assert_eq!(B0::select("then", "otherwise"), "otherwise");
+    /// ```
+    fn select<T>(then: T, otherwise: T) -> T;
 }
 
 /// The **marker trait** for compile time unsigned integers.
@@ -153,6 +164,21 @@ pub trait Integer: Sealed + Copy + Default + 'static {
     fn to_isize() -> isize;
 }
 
+/// The **marker trait** for compile time rational numbers.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Ratio, Rational, N3, U4};
+///
+/// assert_eq!(Ratio::<N3, U4>::to_f64(), -0.75);
+/// ```
+pub trait Rational: Sealed + Copy + Default + 'static {
+    #[allow(missing_docs)]
+    fn to_f32() -> f32;
+    #[allow(missing_docs)]
+    fn to_f64() -> f64;
+}
+
 /// The **marker trait** for type-level arrays of type-level numbers.
 ///
 /// Someday, it may contain an associated constant to produce a runtime array,
@@ -160,6 +186,9 @@ pub trait Integer: Sealed + Copy + Default + 'static {
 /// issue](https://github.com/rust-lang/rust/issues/44168).
 pub trait TypeArray: Sealed {}
 
+/// The **marker trait** for type-level options, `TSome` and `TNone`.
+pub trait TypeOption: Sealed {}
+
 /// The **marker trait** for type-level numbers which are a power of two.
 ///
 /// # Examples
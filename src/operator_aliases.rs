@@ -18,9 +18,10 @@
 
 // Aliases!!!
 use crate::type_operators::{
-    Abs, Cmp, Gcd, Len, Logarithm2, Max, Min, PartialDiv, Pow, SquareRoot,
+    Abs, CheckedDiv, CheckedPartialDiv, CheckedRem, CheckedSub, Cmp, Gcd, IsSome, Lcm, Len,
+    Logarithm2, Max, Min, PartialDiv, Pow, SquareRoot, UnwrapOr,
 };
-use core::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Rem, Shl, Shr, Sub};
+use core::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Not, Rem, Shl, Shr, Sub};
 
 /// Alias for the associated type of `BitAnd`: `And<A, B> = <A as BitAnd<B>>::Output`
 pub type And<A, B> = <A as BitAnd<B>>::Output;
@@ -28,6 +29,8 @@ pub type And<A, B> = <A as BitAnd<B>>::Output;
 pub type Or<A, B> = <A as BitOr<B>>::Output;
 /// Alias for the associated type of `BitXor`: `Xor<A, B> = <A as BitXor<B>>::Output`
 pub type Xor<A, B> = <A as BitXor<B>>::Output;
+/// Alias for the associated type of `Not`: `BitNot<A> = <A as Not>::Output`
+pub type BitNot<A> = <A as Not>::Output;
 
 /// Alias for the associated type of `Shl`: `Shleft<A, B> = <A as Shl<B>>::Output`
 pub type Shleft<A, B> = <A as Shl<B>>::Output;
@@ -61,6 +64,17 @@ pub type Exp<A, B> = <A as Pow<B>>::Output;
 /// Alias for the associated type of `Gcd`: `Gcf<A, B> = <A as Gcd<B>>::Output>`
 pub type Gcf<A, B> = <A as Gcd<B>>::Output;
 
+/// Alias for the associated type of `Lcm`: `Lcmf<A, B> = <A as Lcm<B>>::Output`
+pub type Lcmf<A, B> = <A as Lcm<B>>::Output;
+
+use crate::type_operators::{Binomial, Factorial, Permutations};
+/// Alias for the associated type of `Factorial`: `Fact<A> = <A as Factorial>::Output`
+pub type Fact<A> = <A as Factorial>::Output;
+/// Alias for the associated type of `Binomial`: `Choose<N, K> = <N as Binomial<K>>::Output`
+pub type Choose<N, K> = <N as Binomial<K>>::Output;
+/// Alias for the associated type of `Permutations`: `Perm<N, K> = <N as Permutations<K>>::Output`
+pub type Perm<N, K> = <N as Permutations<K>>::Output;
+
 /// Alias to make it easy to add 1: `Add1<A> = <A as Add<B1>>::Output`
 pub type Add1<A> = <A as Add<crate::bit::B1>>::Output;
 /// Alias to make it easy to subtract 1: `Sub1<A> = <A as Sub<B1>>::Output`
@@ -77,6 +91,26 @@ pub type Cube<A> = <Square<A> as Mul<A>>::Output;
 /// Alias for the associated type of `SquareRoot`: `Sqrt<A> = <A as SquareRoot>::Output`
 pub type Sqrt<A> = <A as SquareRoot>::Output;
 
+use crate::type_operators::{
+    CeilCubeRoot, CeilNthRoot, CeilSquareRoot, CubeRoot, IsPerfectPower, IsPerfectSquare, NthRoot,
+};
+/// Alias for the associated type of `NthRoot`: `Root<A, K> = <A as NthRoot<K>>::Output`
+pub type Root<A, K> = <A as NthRoot<K>>::Output;
+/// Alias for the associated type of `CubeRoot`: `Cbrt<A> = <A as CubeRoot>::Output`
+pub type Cbrt<A> = <A as CubeRoot>::Output;
+/// Alias for the associated type of `CeilNthRoot`: `CeilRoot<A, K> = <A as CeilNthRoot<K>>::Output`
+pub type CeilRoot<A, K> = <A as CeilNthRoot<K>>::Output;
+/// Alias for the associated type of `CeilSquareRoot`: `CeilSqrt<A> = <A as CeilSquareRoot>::Output`
+pub type CeilSqrt<A> = <A as CeilSquareRoot>::Output;
+/// Alias for the associated type of `CeilCubeRoot`: `CeilCbrt<A> = <A as CeilCubeRoot>::Output`
+pub type CeilCbrt<A> = <A as CeilCubeRoot>::Output;
+/// Alias for the associated type of
+/// `IsPerfectSquare`: `IsSquare<A> = <A as IsPerfectSquare>::Output`
+pub type IsSquare<A> = <A as IsPerfectSquare>::Output;
+/// Alias for the associated type of
+/// `IsPerfectPower`: `IsPower<A, K> = <A as IsPerfectPower<K>>::Output`
+pub type IsPower<A, K> = <A as IsPerfectPower<K>>::Output;
+
 /// Alias for the associated type of `Cmp`: `Compare<A, B> = <A as Cmp<B>>::Output`
 pub type Compare<A, B> = <A as Cmp<B>>::Output;
 
@@ -89,6 +123,25 @@ pub type Minimum<A, B> = <A as Min<B>>::Output;
 /// Alias for the associated type of `Max`: `Maximum<A, B> = <A as Max<B>>::Output`
 pub type Maximum<A, B> = <A as Max<B>>::Output;
 
+use crate::type_operators::{DivCeil, DivEuclid, DivFloor, RemEuclid};
+/// Alias for the associated type of `DivCeil`: `QuotCeil<A, B> = <A as DivCeil<B>>::Output`
+pub type QuotCeil<A, B> = <A as DivCeil<B>>::Output;
+/// Alias for the associated type of `DivFloor`: `QuotFloor<A, B> = <A as DivFloor<B>>::Output`
+pub type QuotFloor<A, B> = <A as DivFloor<B>>::Output;
+/// Alias for the associated type of `DivEuclid`: `QuotEuclid<A, B> = <A as DivEuclid<B>>::Output`
+pub type QuotEuclid<A, B> = <A as DivEuclid<B>>::Output;
+/// Alias for the associated type of `RemEuclid`: `ModEuclid<A, B> = <A as RemEuclid<B>>::Output`
+pub type ModEuclid<A, B> = <A as RemEuclid<B>>::Output;
+
+use crate::type_operators::{AbsDiff, Clamp, SaturatingSub};
+/// Alias for the associated type of
+/// `SaturatingSub`: `SaturatingDiff<A, B> = <A as SaturatingSub<B>>::Output`
+pub type SaturatingDiff<A, B> = <A as SaturatingSub<B>>::Output;
+/// Alias for the associated type of `AbsDiff`: `AbsDifference<A, B> = <A as AbsDiff<B>>::Output`
+pub type AbsDifference<A, B> = <A as AbsDiff<B>>::Output;
+/// Alias for the associated type of `Clamp`: `Clamped<A, Lo, Hi> = <A as Clamp<Lo, Hi>>::Output`
+pub type Clamped<A, Lo, Hi> = <A as Clamp<Lo, Hi>>::Output;
+
 use crate::type_operators::{
     IsEqual, IsGreater, IsGreaterOrEqual, IsLess, IsLessOrEqual, IsNotEqual,
 };
@@ -107,3 +160,183 @@ pub type LeEq<A, B> = <A as IsLessOrEqual<B>>::Output;
 pub type NotEq<A, B> = <A as IsNotEqual<B>>::Output;
 /// Alias for the associated type of `Logarithm2`: `Log2<A> = <A as Logarithm2>::Output`
 pub type Log2<A> = <A as Logarithm2>::Output;
+
+use crate::type_operators::{CeilLogarithm, CeilLogarithm2, Logarithm};
+/// Alias for the associated type of `CeilLogarithm2`: `CeilLog2<A> = <A as CeilLogarithm2>::Output`
+pub type CeilLog2<A> = <A as CeilLogarithm2>::Output;
+/// Alias for the associated type of `Logarithm`: `Log<A, B> = <A as Logarithm<B>>::Output`
+pub type Log<A, B> = <A as Logarithm<B>>::Output;
+/// Alias for the associated type of
+/// `CeilLogarithm`: `CeilLog<A, B> = <A as CeilLogarithm<B>>::Output`
+pub type CeilLog<A, B> = <A as CeilLogarithm<B>>::Output;
+
+/// Alias for the associated type of
+/// `CheckedSub`: `CheckedDiff<A, B> = <A as CheckedSub<B>>::Output`
+pub type CheckedDiff<A, B> = <A as CheckedSub<B>>::Output;
+/// Alias for the associated type of
+/// `CheckedDiv`: `CheckedQuot<A, B> = <A as CheckedDiv<B>>::Output`
+pub type CheckedQuot<A, B> = <A as CheckedDiv<B>>::Output;
+/// Alias for the associated type of `CheckedRem`: `CheckedMod<A, B> = <A as CheckedRem<B>>::Output`
+pub type CheckedMod<A, B> = <A as CheckedRem<B>>::Output;
+/// Alias for the associated type of
+/// `CheckedPartialDiv`: `CheckedPartialQuot<A, B> = <A as CheckedPartialDiv<B>>::Output`
+pub type CheckedPartialQuot<A, B> = <A as CheckedPartialDiv<B>>::Output;
+/// Alias for the associated type of `IsSome`: `IsSomeOut<A> = <A as IsSome>::Output`
+pub type IsSomeOut<A> = <A as IsSome>::Output;
+/// Alias for the associated type of `UnwrapOr`: `UnwrapOrOut<A, F> = <A as UnwrapOr<F>>::Output`
+pub type UnwrapOrOut<A, F> = <A as UnwrapOr<F>>::Output;
+
+use crate::type_operators::{AlignDown, AlignUp, IsPowerOfTwo, NextPowerOfTwo, PrevPowerOfTwo};
+/// Alias for the associated type of `IsPowerOfTwo`: `IsPow2<A> = <A as IsPowerOfTwo>::Output`
+pub type IsPow2<A> = <A as IsPowerOfTwo>::Output;
+/// Alias for the associated type of `NextPowerOfTwo`: `NextPow2<A> = <A as NextPowerOfTwo>::Output`
+pub type NextPow2<A> = <A as NextPowerOfTwo>::Output;
+/// Alias for the associated type of `PrevPowerOfTwo`: `PrevPow2<A> = <A as PrevPowerOfTwo>::Output`
+pub type PrevPow2<A> = <A as PrevPowerOfTwo>::Output;
+/// Alias for the associated type of `AlignUp`: `AlignedUp<N, A> = <N as AlignUp<A>>::Output`
+pub type AlignedUp<N, A> = <N as AlignUp<A>>::Output;
+/// Alias for the associated type of `AlignDown`: `AlignedDown<N, A> = <N as AlignDown<A>>::Output`
+pub type AlignedDown<N, A> = <N as AlignDown<A>>::Output;
+
+use crate::type_operators::{CountOnes, CountZeros, LeadingZeros, Parity, TrailingZeros};
+/// Alias for the associated type of `CountOnes`: `NumOnes<A> = <A as CountOnes>::Output`
+pub type NumOnes<A> = <A as CountOnes>::Output;
+/// Alias for the associated type of `CountZeros`: `NumZeros<A, W> = <A as CountZeros<W>>::Output`
+pub type NumZeros<A, W> = <A as CountZeros<W>>::Output;
+/// Alias for the associated type of
+/// `TrailingZeros`: `NumTrailingZeros<A> = <A as TrailingZeros>::Output`
+pub type NumTrailingZeros<A> = <A as TrailingZeros>::Output;
+/// Alias for the associated type of
+/// `LeadingZeros`: `NumLeadingZeros<A, W> = <A as LeadingZeros<W>>::Output`
+pub type NumLeadingZeros<A, W> = <A as LeadingZeros<W>>::Output;
+/// Alias for the associated type of `Parity`: `ParityBit<A> = <A as Parity>::Output`
+pub type ParityBit<A> = <A as Parity>::Output;
+
+use crate::type_operators::{Factorize, IsPrime};
+/// Alias for the associated type of `IsPrime`: `Prime<A> = <A as IsPrime>::Output`
+pub type Prime<A> = <A as IsPrime>::Output;
+/// Alias for the associated type of `Factorize`: `Factors<A> = <A as Factorize>::Output`
+pub type Factors<A> = <A as Factorize>::Output;
+
+use crate::type_operators::{DivisorCount, DivisorSum, Divisors, Totient};
+/// Alias for the associated type of `Totient`: `Phi<A> = <A as Totient>::Output`
+pub type Phi<A> = <A as Totient>::Output;
+/// Alias for the associated type of `Divisors`: `DivisorList<A> = <A as Divisors>::Output`
+pub type DivisorList<A> = <A as Divisors>::Output;
+/// Alias for the associated type of `DivisorCount`: `NumDivisors<A> = <A as DivisorCount>::Output`
+pub type NumDivisors<A> = <A as DivisorCount>::Output;
+/// Alias for the associated type of `DivisorSum`: `SumOfDivisors<A> = <A as DivisorSum>::Output`
+pub type SumOfDivisors<A> = <A as DivisorSum>::Output;
+
+use crate::type_operators::{ModAdd, ModInverse, ModMul, ModPow};
+/// Alias for the associated type of `ModAdd`: `ModSum<A, B, M> = <A as ModAdd<B, M>>::Output`
+pub type ModSum<A, B, M> = <A as ModAdd<B, M>>::Output;
+/// Alias for the associated type of `ModMul`: `ModProd<A, B, M> = <A as ModMul<B, M>>::Output`
+pub type ModProd<A, B, M> = <A as ModMul<B, M>>::Output;
+/// Alias for the associated type of `ModPow`: `ModExp<A, E, M> = <A as ModPow<E, M>>::Output`
+pub type ModExp<A, E, M> = <A as ModPow<E, M>>::Output;
+/// Alias for the associated type of `ModInverse`: `ModInv<A, M> = <A as ModInverse<M>>::Output`
+pub type ModInv<A, M> = <A as ModInverse<M>>::Output;
+
+use crate::type_operators::ExtendedGcd;
+/// Alias for the associated type of `ExtendedGcd`: `ExtGcd<A, B> = <A as ExtendedGcd<B>>::Output`
+pub type ExtGcd<A, B> = <A as ExtendedGcd<B>>::Output;
+
+use crate::type_operators::{IsEven, IsNegative, IsOdd, IsPositive, IsZero, Signum};
+/// Alias for the associated type of `Signum`: `Sgn<A> = <A as Signum>::Output`
+pub type Sgn<A> = <A as Signum>::Output;
+/// Alias for the associated type of `IsNegative`: `IsNegativeOut<A> = <A as IsNegative>::Output`
+pub type IsNegativeOut<A> = <A as IsNegative>::Output;
+/// Alias for the associated type of `IsPositive`: `IsPositiveOut<A> = <A as IsPositive>::Output`
+pub type IsPositiveOut<A> = <A as IsPositive>::Output;
+/// Alias for the associated type of `IsZero`: `IsZeroOut<A> = <A as IsZero>::Output`
+pub type IsZeroOut<A> = <A as IsZero>::Output;
+/// Alias for the associated type of `IsEven`: `IsEvenOut<A> = <A as IsEven>::Output`
+pub type IsEvenOut<A> = <A as IsEven>::Output;
+/// Alias for the associated type of `IsOdd`: `IsOddOut<A> = <A as IsOdd>::Output`
+pub type IsOddOut<A> = <A as IsOdd>::Output;
+
+use crate::type_operators::{BitToInteger, BitToUnsigned, ToSigned, ToUnsigned};
+/// Alias for the associated type of `ToSigned`: `AsSigned<A> = <A as ToSigned>::Output`
+pub type AsSigned<A> = <A as ToSigned>::Output;
+/// Alias for the associated type of `ToUnsigned`: `AsUnsigned<A> = <A as ToUnsigned>::Output`
+pub type AsUnsigned<A> = <A as ToUnsigned>::Output;
+/// Alias for the associated type of
+/// `BitToUnsigned`: `BitAsUnsigned<A> = <A as BitToUnsigned>::Output`
+pub type BitAsUnsigned<A> = <A as BitToUnsigned>::Output;
+/// Alias for the associated type of `BitToInteger`: `BitAsInteger<A> = <A as BitToInteger>::Output`
+pub type BitAsInteger<A> = <A as BitToInteger>::Output;
+
+use crate::type_operators::If;
+/// Alias for the associated type of `If`: `IfElse<C, T, E> = <C as If<T, E>>::Output`
+pub type IfElse<C, T, E> = <C as If<T, E>>::Output;
+
+use crate::type_operators::{FullAdd, FullSub, Implies, Nand, Nor, Xnor};
+/// Alias for the associated type of `Nand`: `NandOut<A, B> = <A as Nand<B>>::Output`
+pub type NandOut<A, B> = <A as Nand<B>>::Output;
+/// Alias for the associated type of `Nor`: `NorOut<A, B> = <A as Nor<B>>::Output`
+pub type NorOut<A, B> = <A as Nor<B>>::Output;
+/// Alias for the associated type of `Xnor`: `XnorOut<A, B> = <A as Xnor<B>>::Output`
+pub type XnorOut<A, B> = <A as Xnor<B>>::Output;
+/// Alias for the associated type of `Implies`: `ImpliesOut<A, B> = <A as Implies<B>>::Output`
+pub type ImpliesOut<A, B> = <A as Implies<B>>::Output;
+/// Alias for the sum bit of `FullAdd`: `FullSum<A, B, C> = <A as FullAdd<B, C>>::Output`
+pub type FullSum<A, B, C> = <A as FullAdd<B, C>>::Output;
+/// Alias for the carry out of `FullAdd`: `FullCarry<A, B, C> = <A as FullAdd<B, C>>::Carry`
+pub type FullCarry<A, B, C> = <A as FullAdd<B, C>>::Carry;
+/// Alias for the difference bit of `FullSub`: `FullDiff<A, B, C> = <A as FullSub<B, C>>::Output`
+pub type FullDiff<A, B, C> = <A as FullSub<B, C>>::Output;
+/// Alias for the borrow out of `FullSub`: `FullBorrow<A, B, C> = <A as FullSub<B, C>>::Borrow`
+pub type FullBorrow<A, B, C> = <A as FullSub<B, C>>::Borrow;
+
+use crate::type_operators::{
+    OrdIsEqual, OrdIsGreater, OrdIsGreaterOrEqual, OrdIsLess, OrdIsLessOrEqual, OrdIsNotEqual,
+    OrdReverse, OrdThen, OrdToInt,
+};
+/// Alias for the associated type of `OrdReverse`: `OrdReverseOut<A> = <A as OrdReverse>::Output`
+pub type OrdReverseOut<A> = <A as OrdReverse>::Output;
+/// Alias for the associated type of `OrdThen`: `OrdThenOut<A, B> = <A as OrdThen<B>>::Output`
+pub type OrdThenOut<A, B> = <A as OrdThen<B>>::Output;
+/// Alias for the associated type of `OrdToInt`: `OrdToIntOut<A> = <A as OrdToInt>::Output`
+pub type OrdToIntOut<A> = <A as OrdToInt>::Output;
+/// Alias for the associated type of `OrdIsLess`: `OrdIsLessOut<A> = <A as OrdIsLess>::Output`
+pub type OrdIsLessOut<A> = <A as OrdIsLess>::Output;
+/// Alias for the associated type of
+/// `OrdIsLessOrEqual`: `OrdIsLessOrEqualOut<A> = <A as OrdIsLessOrEqual>::Output`
+pub type OrdIsLessOrEqualOut<A> = <A as OrdIsLessOrEqual>::Output;
+/// Alias for the associated type of `OrdIsEqual`: `OrdIsEqualOut<A> = <A as OrdIsEqual>::Output`
+pub type OrdIsEqualOut<A> = <A as OrdIsEqual>::Output;
+/// Alias for the associated type of
+/// `OrdIsNotEqual`: `OrdIsNotEqualOut<A> = <A as OrdIsNotEqual>::Output`
+pub type OrdIsNotEqualOut<A> = <A as OrdIsNotEqual>::Output;
+/// Alias for the associated type of
+/// `OrdIsGreater`: `OrdIsGreaterOut<A> = <A as OrdIsGreater>::Output`
+pub type OrdIsGreaterOut<A> = <A as OrdIsGreater>::Output;
+/// Alias for the associated type of
+/// `OrdIsGreaterOrEqual`: `OrdIsGreaterOrEqualOut<A> = <A as OrdIsGreaterOrEqual>::Output`
+pub type OrdIsGreaterOrEqualOut<A> = <A as OrdIsGreaterOrEqual>::Output;
+
+use crate::type_operators::{WrappingAdd, WrappingMul, WrappingNeg, WrappingNot, WrappingSub};
+/// Alias for the associated type of
+/// `WrappingAdd`: `WrappingSum<A, B, W> = <A as WrappingAdd<B, W>>::Output`
+pub type WrappingSum<A, B, W> = <A as WrappingAdd<B, W>>::Output;
+/// Alias for the associated type of
+/// `WrappingSub`: `WrappingDiff<A, B, W> = <A as WrappingSub<B, W>>::Output`
+pub type WrappingDiff<A, B, W> = <A as WrappingSub<B, W>>::Output;
+/// Alias for the associated type of
+/// `WrappingMul`: `WrappingProd<A, B, W> = <A as WrappingMul<B, W>>::Output`
+pub type WrappingProd<A, B, W> = <A as WrappingMul<B, W>>::Output;
+/// Alias for the associated type of
+/// `WrappingNeg`: `WrappingNegate<A, W> = <A as WrappingNeg<W>>::Output`
+pub type WrappingNegate<A, W> = <A as WrappingNeg<W>>::Output;
+/// Alias for the associated type of
+/// `WrappingNot`: `WrappingBitNot<A, W> = <A as WrappingNot<W>>::Output`
+pub type WrappingBitNot<A, W> = <A as WrappingNot<W>>::Output;
+
+use crate::type_operators::{FromTwosComplement, ToTwosComplement};
+/// Alias for the associated type of
+/// `ToTwosComplement`: `TwosComp<A, W> = <A as ToTwosComplement<W>>::Output`
+pub type TwosComp<A, W> = <A as ToTwosComplement<W>>::Output;
+/// Alias for the associated type of
+/// `FromTwosComplement`: `FromTwosComp<A, W> = <A as FromTwosComplement<W>>::Output`
+pub type FromTwosComp<A, W> = <A as FromTwosComplement<W>>::Output;
@@ -0,0 +1,345 @@
+//! A type-level option.
+//!
+//! `TSome<T>` and `TNone` are the type-level counterparts of `Some(t)` and `None`. They are
+//! the results of the checked type operators, which give `TNone` where the unchecked operator
+//! would have no implementation, instead of failing to compile.
+//!
+//! **Type operators** implemented:
+//!
+//! From `typenum`: `CheckedSub`, `CheckedDiv`, `CheckedRem`, `CheckedPartialDiv`, `IsSome`,
+//! and `UnwrapOr`.
+//!
+//! # Example
+//! ```rust
+//! use typenum::{assert_type_eq, CheckedDiff, CheckedQuot, IsSomeOut, UnwrapOrOut};
+//! use typenum::{False, TNone, TSome, U0, U2, U3, U6};
+//!
+//! assert_type_eq!(CheckedDiff<U6, U3>, TSome<U3>);
+//! assert_type_eq!(CheckedDiff<U3, U6>, TNone);
+//! assert_type_eq!(IsSomeOut<CheckedQuot<U6, U0>>, False);
+//! assert_type_eq!(UnwrapOrOut<CheckedQuot<U6, U0>, U2>, U2);
+//! ```
+
+use crate::{
+    private::{
+        PrivateCheckedPartialDiv, PrivateCheckedPartialDivOut, PrivateCheckedSub,
+        PrivateCheckedSubOut,
+    },
+    Bit, CheckedDiv, CheckedPartialDiv, CheckedRem, CheckedSub, Cmp, Compare, Diff, Equal, Greater,
+    Integer, IsSome, Less, Mod, NInt, NonZero, PInt, Quot, TypeOption, UInt, UTerm, Unsigned,
+    UnwrapOr, B0, B1, U0, Z0,
+};
+use core::ops::{Div, Rem, Sub};
+
+/// The type-level counterpart of `None`.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug, Default)]
+#[cfg_attr(feature = "scale_info", derive(scale_info::TypeInfo))]
+pub struct TNone;
+
+impl TNone {
+    /// Instantiates a singleton representing the absence of a value.
+    #[inline]
+    pub fn new() -> TNone {
+        TNone
+    }
+}
+
+impl TypeOption for TNone {}
+
+/// The type-level counterpart of `Some(T)`.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug, Default)]
+#[cfg_attr(feature = "scale_info", derive(scale_info::TypeInfo))]
+pub struct TSome<T> {
+    value: T,
+}
+
+impl<T: Default> TSome<T> {
+    /// Instantiates a singleton representing this present value.
+    #[inline]
+    pub fn new() -> TSome<T> {
+        TSome::default()
+    }
+}
+
+impl<T> TypeOption for TSome<T> {}
+
+// ---------------------------------------------------------------------------------------
+// IsSome
+
+impl IsSome for TNone {
+    type Output = B0;
+}
+
+impl<T> IsSome for TSome<T> {
+    type Output = B1;
+}
+
+// ---------------------------------------------------------------------------------------
+// UnwrapOr
+
+impl<Fallback> UnwrapOr<Fallback> for TNone {
+    type Output = Fallback;
+}
+
+impl<T, Fallback> UnwrapOr<Fallback> for TSome<T> {
+    type Output = T;
+}
+
+// ---------------------------------------------------------------------------------------
+// CheckedSub
+
+impl<A, B> PrivateCheckedSub<B, Less> for A {
+    type Output = TNone;
+}
+
+impl<A, B> PrivateCheckedSub<B, Equal> for A
+where
+    A: Sub<B>,
+{
+    type Output = TSome<Diff<A, B>>;
+}
+
+impl<A, B> PrivateCheckedSub<B, Greater> for A
+where
+    A: Sub<B>,
+{
+    type Output = TSome<Diff<A, B>>;
+}
+
+/// `UTerm - U` is `TNone` if `U > 0`
+impl<Ur> CheckedSub<Ur> for UTerm
+where
+    Ur: Unsigned,
+    UTerm: Cmp<Ur> + PrivateCheckedSub<Ur, Compare<UTerm, Ur>>,
+{
+    type Output = PrivateCheckedSubOut<UTerm, Ur, Compare<UTerm, Ur>>;
+}
+
+/// `UInt - U` is `TNone` if `U > UInt`
+impl<Ul, Bl, Ur> CheckedSub<Ur> for UInt<Ul, Bl>
+where
+    Ul: Unsigned,
+    Bl: Bit,
+    Ur: Unsigned,
+    UInt<Ul, Bl>: Cmp<Ur> + PrivateCheckedSub<Ur, Compare<UInt<Ul, Bl>, Ur>>,
+{
+    type Output = PrivateCheckedSubOut<UInt<Ul, Bl>, Ur, Compare<UInt<Ul, Bl>, Ur>>;
+}
+
+/// Subtraction of signed integers never fails
+impl<I> CheckedSub<I> for Z0
+where
+    I: Integer,
+    Z0: Sub<I>,
+{
+    type Output = TSome<Diff<Z0, I>>;
+}
+
+/// Subtraction of signed integers never fails
+impl<U, I> CheckedSub<I> for PInt<U>
+where
+    U: Unsigned + NonZero,
+    I: Integer,
+    PInt<U>: Sub<I>,
+{
+    type Output = TSome<Diff<PInt<U>, I>>;
+}
+
+/// Subtraction of signed integers never fails
+impl<U, I> CheckedSub<I> for NInt<U>
+where
+    U: Unsigned + NonZero,
+    I: Integer,
+    NInt<U>: Sub<I>,
+{
+    type Output = TSome<Diff<NInt<U>, I>>;
+}
+
+// ---------------------------------------------------------------------------------------
+// CheckedDiv
+
+/// `N / 0` is `TNone`
+impl<N: Unsigned> CheckedDiv<U0> for N {
+    type Output = TNone;
+}
+
+impl<N, Ur, Br> CheckedDiv<UInt<Ur, Br>> for N
+where
+    N: Unsigned + Div<UInt<Ur, Br>>,
+{
+    type Output = TSome<Quot<N, UInt<Ur, Br>>>;
+}
+
+/// `I / 0` is `TNone`
+impl<I: Integer> CheckedDiv<Z0> for I {
+    type Output = TNone;
+}
+
+impl<I, U> CheckedDiv<PInt<U>> for I
+where
+    I: Integer + Div<PInt<U>>,
+    U: Unsigned + NonZero,
+{
+    type Output = TSome<Quot<I, PInt<U>>>;
+}
+
+impl<I, U> CheckedDiv<NInt<U>> for I
+where
+    I: Integer + Div<NInt<U>>,
+    U: Unsigned + NonZero,
+{
+    type Output = TSome<Quot<I, NInt<U>>>;
+}
+
+// ---------------------------------------------------------------------------------------
+// CheckedRem
+
+/// `N % 0` is `TNone`
+impl<N: Unsigned> CheckedRem<U0> for N {
+    type Output = TNone;
+}
+
+impl<N, Ur, Br> CheckedRem<UInt<Ur, Br>> for N
+where
+    N: Unsigned + Rem<UInt<Ur, Br>>,
+{
+    type Output = TSome<Mod<N, UInt<Ur, Br>>>;
+}
+
+/// `I % 0` is `TNone`
+impl<I: Integer> CheckedRem<Z0> for I {
+    type Output = TNone;
+}
+
+impl<I, U> CheckedRem<PInt<U>> for I
+where
+    I: Integer + Rem<PInt<U>>,
+    U: Unsigned + NonZero,
+{
+    type Output = TSome<Mod<I, PInt<U>>>;
+}
+
+impl<I, U> CheckedRem<NInt<U>> for I
+where
+    I: Integer + Rem<NInt<U>>,
+    U: Unsigned + NonZero,
+{
+    type Output = TSome<Mod<I, NInt<U>>>;
+}
+
+// ---------------------------------------------------------------------------------------
+// CheckedPartialDiv
+
+impl<A, B> PrivateCheckedPartialDiv<B, U0> for A
+where
+    A: Div<B>,
+{
+    type Output = TSome<Quot<A, B>>;
+}
+
+impl<A, B, U, Bu> PrivateCheckedPartialDiv<B, UInt<U, Bu>> for A {
+    type Output = TNone;
+}
+
+impl<A, B> PrivateCheckedPartialDiv<B, Z0> for A
+where
+    A: Div<B>,
+{
+    type Output = TSome<Quot<A, B>>;
+}
+
+impl<A, B, U> PrivateCheckedPartialDiv<B, PInt<U>> for A
+where
+    U: Unsigned + NonZero,
+{
+    type Output = TNone;
+}
+
+impl<A, B, U> PrivateCheckedPartialDiv<B, NInt<U>> for A
+where
+    U: Unsigned + NonZero,
+{
+    type Output = TNone;
+}
+
+/// `N / 0` is `TNone`
+impl<N: Unsigned> CheckedPartialDiv<U0> for N {
+    type Output = TNone;
+}
+
+/// `N / D` is `TNone` if `N % D != 0`
+impl<N, Ur, Br> CheckedPartialDiv<UInt<Ur, Br>> for N
+where
+    N: Unsigned + Rem<UInt<Ur, Br>> + PrivateCheckedPartialDiv<UInt<Ur, Br>, Mod<N, UInt<Ur, Br>>>,
+{
+    type Output = PrivateCheckedPartialDivOut<N, UInt<Ur, Br>, Mod<N, UInt<Ur, Br>>>;
+}
+
+/// `I / 0` is `TNone`
+impl<I: Integer> CheckedPartialDiv<Z0> for I {
+    type Output = TNone;
+}
+
+/// `I / D` is `TNone` if `I % D != 0`
+impl<I, U> CheckedPartialDiv<PInt<U>> for I
+where
+    I: Integer + Rem<PInt<U>> + PrivateCheckedPartialDiv<PInt<U>, Mod<I, PInt<U>>>,
+    U: Unsigned + NonZero,
+{
+    type Output = PrivateCheckedPartialDivOut<I, PInt<U>, Mod<I, PInt<U>>>;
+}
+
+/// `I / D` is `TNone` if `I % D != 0`
+impl<I, U> CheckedPartialDiv<NInt<U>> for I
+where
+    I: Integer + Rem<NInt<U>> + PrivateCheckedPartialDiv<NInt<U>, Mod<I, NInt<U>>>,
+    U: Unsigned + NonZero,
+{
+    type Output = PrivateCheckedPartialDivOut<I, NInt<U>, Mod<I, NInt<U>>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        assert_type_eq, consts::*, CheckedDiff, CheckedMod, CheckedPartialQuot, CheckedQuot,
+        IsSomeOut, TNone, TSome, UnwrapOrOut,
+    };
+
+    #[test]
+    fn checked_unsigned() {
+        assert_type_eq!(CheckedDiff<U5, U5>, TSome<U0>);
+        assert_type_eq!(CheckedDiff<U0, U1>, TNone);
+        assert_type_eq!(CheckedQuot<U7, U2>, TSome<U3>);
+        assert_type_eq!(CheckedQuot<U7, U0>, TNone);
+        assert_type_eq!(CheckedMod<U7, U2>, TSome<U1>);
+        assert_type_eq!(CheckedMod<U0, U0>, TNone);
+        assert_type_eq!(CheckedPartialQuot<U8, U2>, TSome<U4>);
+        assert_type_eq!(CheckedPartialQuot<U7, U2>, TNone);
+        assert_type_eq!(CheckedPartialQuot<U0, U2>, TSome<U0>);
+    }
+
+    #[test]
+    fn checked_signed() {
+        assert_type_eq!(CheckedDiff<N3, P4>, TSome<N7>);
+        assert_type_eq!(CheckedQuot<N7, P2>, TSome<N3>);
+        assert_type_eq!(CheckedQuot<Z0, Z0>, TNone);
+        assert_type_eq!(CheckedMod<N7, P2>, TSome<N1>);
+        assert_type_eq!(CheckedMod<P7, Z0>, TNone);
+        assert_type_eq!(CheckedPartialQuot<N8, N2>, TSome<P4>);
+        assert_type_eq!(CheckedPartialQuot<N7, P2>, TNone);
+    }
+
+    #[test]
+    fn option_ops() {
+        assert_type_eq!(IsSomeOut<TSome<U3>>, True);
+        assert_type_eq!(IsSomeOut<TNone>, False);
+        assert_type_eq!(UnwrapOrOut<TSome<U3>, U4>, U3);
+        assert_type_eq!(UnwrapOrOut<TNone, U4>, U4);
+    }
+
+    #[test]
+    fn option_new() {
+        assert_eq!(TNone::new(), TNone);
+        assert_eq!(TSome::<U3>::new(), TSome::default());
+    }
+}
@@ -585,3 +585,116 @@ pub trait PrivateSquareRoot {
 pub trait PrivateLogarithm2 {
     type Output;
 }
+
+/// Trial division for primality testing; `Done = Divisor * Divisor > Self` and
+/// `Remainder = Self % Divisor`
+pub trait PrivateIsPrime<Divisor, Done, Remainder> {
+    type Output;
+}
+pub type PrivateIsPrimeOut<N, Divisor, Done, Remainder> =
+    <N as PrivateIsPrime<Divisor, Done, Remainder>>::Output;
+
+/// Factorization of `Self`, trying divisors starting at `Divisor`
+pub trait PrivateFactorizeFrom<Divisor> {
+    type Output;
+}
+pub type PrivateFactorizeFromOut<N, Divisor> = <N as PrivateFactorizeFrom<Divisor>>::Output;
+
+/// Trial division for factorization; `Done = Divisor * Divisor > Self` and
+/// `Remainder = Self % Divisor`
+pub trait PrivateFactorize<Divisor, Done, Remainder> {
+    type Output;
+}
+pub type PrivateFactorizeOut<N, Divisor, Done, Remainder> =
+    <N as PrivateFactorize<Divisor, Done, Remainder>>::Output;
+
+/// Appends `V` to the end of a type array
+pub trait PrivateAppend<V> {
+    type Output;
+}
+pub type PrivateAppendOut<A, V> = <A as PrivateAppend<V>>::Output;
+
+/// Sums the elements of a type array
+pub trait PrivateArraySum {
+    type Output;
+}
+pub type PrivateArraySumOut<A> = <A as PrivateArraySum>::Output;
+
+/// Divisors of `Self`, trying divisors starting at `Divisor`
+pub trait PrivateDivisorsFrom<Divisor> {
+    type Output;
+}
+pub type PrivateDivisorsFromOut<N, Divisor> = <N as PrivateDivisorsFrom<Divisor>>::Output;
+
+/// Trial division for divisors; `CmpResult = (Divisor * Divisor).cmp(Self)` and
+/// `Remainder = Self % Divisor`
+pub trait PrivateDivisors<Divisor, CmpResult, Remainder> {
+    type Output;
+}
+pub type PrivateDivisorsOut<N, Divisor, CmpResult, Remainder> =
+    <N as PrivateDivisors<Divisor, CmpResult, Remainder>>::Output;
+
+/// Euler's totient of the product of the prime factors in a sorted type array, where `Prev` is
+/// the factor before this array
+pub trait PrivateTotient<Prev> {
+    type Output;
+}
+pub type PrivateTotientOut<A, Prev> = <A as PrivateTotient<Prev>>::Output;
+
+/// One step of the extended Euclidean algorithm for the inverse of `A` modulo `M`, where
+/// `Self` and `R` are the last two remainders and `OldS` and `S` are their coefficients of `A`,
+/// reduced modulo `M` so that they stay unsigned
+pub trait PrivateModInverse<M, R, OldS, S> {
+    type Output;
+}
+pub type PrivateModInverseOut<OldR, M, R, OldS, S> =
+    <OldR as PrivateModInverse<M, R, OldS, S>>::Output;
+
+/// Used for the integer logarithm; `CmpResult = Self.cmp(Base)`
+pub trait PrivateLogarithm<Base, CmpResult> {
+    type Output;
+}
+pub type PrivateLogarithmOut<A, Base, CmpResult> = <A as PrivateLogarithm<Base, CmpResult>>::Output;
+
+/// Converts an unsigned integer to the signed integer with the same value.
+pub trait PrivateToSigned {
+    type Output;
+
+    fn private_to_signed(self) -> Self::Output;
+}
+pub type PrivateToSignedOut<A> = <A as PrivateToSigned>::Output;
+
+/// Used for checked subtraction; `CmpResult = Self.cmp(Rhs)`
+pub trait PrivateCheckedSub<Rhs, CmpResult> {
+    type Output;
+}
+pub type PrivateCheckedSubOut<A, Rhs, CmpResult> = <A as PrivateCheckedSub<Rhs, CmpResult>>::Output;
+
+/// Used for checked partial division; `Remainder = Self % Rhs`
+pub trait PrivateCheckedPartialDiv<Rhs, Remainder> {
+    type Output;
+}
+pub type PrivateCheckedPartialDivOut<A, Rhs, Remainder> =
+    <A as PrivateCheckedPartialDiv<Rhs, Remainder>>::Output;
+
+/// Puts a `Ratio` into lowest terms.
+pub trait PrivateReduce {
+    type Output;
+
+    fn private_reduce(self) -> Self::Output;
+}
+pub type PrivateReduceOut<A> = <A as PrivateReduce>::Output;
+
+/// One step back up the extended Euclidean algorithm: `Self` is `[G, X, Y]` for
+/// `(B, A % B)`, and the output is `[G, Y, X - Quotient * Y]` for `(A, B)`
+pub trait PrivateExtendedGcd<Quotient> {
+    type Output;
+}
+pub type PrivateExtendedGcdOut<A, Quotient> = <A as PrivateExtendedGcd<Quotient>>::Output;
+
+/// Decodes a two's complement number of width `W`; `CmpResult = Self.cmp(2^(W-1))`
+pub trait PrivateFromTwosComplement<W, CmpResult> {
+    type Output;
+}
+pub type PrivateFromTwosComplementOut<A, W, CmpResult> =
+    <A as PrivateFromTwosComplement<W, CmpResult>>::Output;
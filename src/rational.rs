@@ -0,0 +1,547 @@
+//! Type-level rational numbers.
+//!
+//! A `Ratio<N, D>` is the rational number `N / D`, where the numerator `N` is a type-level
+//! signed integer and the denominator `D` is a non-zero type-level unsigned integer.
+//!
+//! All type operators here give their result in lowest terms, with the sign carried by the
+//! numerator, so two results that are equal as numbers are also the same type. If you write a
+//! `Ratio` out by hand, write it in lowest terms as well, or `Same` will tell it apart from the
+//! equivalent reduced one.
+//!
+//! **Type operators** implemented:
+//!
+//! From `core::ops`: `Add`, `Sub`, `Mul`, `Div`, and `Neg`.
+//! From `typenum`: `Same`, `Cmp`, `Min`, `Max`, `Abs`, and `Pow`.
+//!
+//! `Pow` is also implemented for signed integers with a negative exponent, giving a `Ratio`.
+//!
+//! # Example
+//! ```rust
+//! use std::ops::{Add, Div};
+//! use typenum::{assert_type_eq, Pow, Ratio, Rational, N2, P1, P10, P2, P36, P5, U1, U18, U2, U3};
+//!
+//! type Half = Ratio<P1, U2>;
+//! type Third = Ratio<P1, U3>;
+//! assert_eq!(<Half as Add<Third>>::Output::to_f64(), 5.0 / 6.0);
+//!
+//! // Conversion factor from km/h to m/s
+//! type KmPerH = <Ratio<P10, U1> as Div<Ratio<P36, U1>>>::Output;
+//! assert_type_eq!(KmPerH, Ratio<P5, U18>);
+//!
+//! assert_eq!(<P2 as Pow<N2>>::Output::to_f64(), 0.25);
+//! ```
+
+use crate::{
+    private::{
+        Internal, InternalMarker, PrivateMax, PrivateMaxOut, PrivateMin, PrivateMinOut,
+        PrivateReduce, PrivateReduceOut,
+    },
+    Abs, AbsVal, Bit, Cmp, Compare, Diff, Equal, Exp, Gcd, Gcf, Greater, Integer, Less, Max, Min,
+    NInt, Negate, NonZero, PInt, PartialDiv, PartialQuot, Pow, Prod, Rational, Sum, UInt, Unsigned,
+    N1, P1, U1, Z0,
+};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Type-level rational numbers, `N / D`.
+#[derive(Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash, Debug, Default)]
+#[cfg_attr(feature = "scale_info", derive(scale_info::TypeInfo))]
+pub struct Ratio<N: Integer, D: Unsigned + NonZero> {
+    pub(crate) n: N,
+    pub(crate) d: D,
+}
+
+impl<N: Integer, D: Unsigned + NonZero> Ratio<N, D> {
+    /// Instantiates a singleton representing this rational number.
+    #[inline]
+    pub fn new() -> Ratio<N, D> {
+        Ratio::default()
+    }
+}
+
+impl<N: Integer, D: Unsigned + NonZero> Rational for Ratio<N, D> {
+    #[inline]
+    fn to_f32() -> f32 {
+        N::to_i64() as f32 / D::to_u64() as f32
+    }
+    #[inline]
+    fn to_f64() -> f64 {
+        N::to_i64() as f64 / D::to_u64() as f64
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Reduce
+
+/// 0 / D = 0 / 1
+impl<D: Unsigned + NonZero> PrivateReduce for Ratio<Z0, D> {
+    type Output = Ratio<Z0, U1>;
+    #[inline]
+    fn private_reduce(self) -> Self::Output {
+        Ratio::new()
+    }
+}
+
+/// N / D = (N / gcd(N, D)) / (D / gcd(N, D))
+impl<U, D> PrivateReduce for Ratio<PInt<U>, D>
+where
+    U: Unsigned + NonZero + Gcd<D> + PartialDiv<Gcf<U, D>>,
+    D: Unsigned + NonZero + PartialDiv<Gcf<U, D>>,
+    PartialQuot<U, Gcf<U, D>>: Unsigned + NonZero,
+    PartialQuot<D, Gcf<U, D>>: Unsigned + NonZero,
+{
+    type Output = Ratio<PInt<PartialQuot<U, Gcf<U, D>>>, PartialQuot<D, Gcf<U, D>>>;
+    #[inline]
+    fn private_reduce(self) -> Self::Output {
+        Ratio::new()
+    }
+}
+
+/// -N / D = -(N / gcd(N, D)) / (D / gcd(N, D))
+impl<U, D> PrivateReduce for Ratio<NInt<U>, D>
+where
+    U: Unsigned + NonZero + Gcd<D> + PartialDiv<Gcf<U, D>>,
+    D: Unsigned + NonZero + PartialDiv<Gcf<U, D>>,
+    PartialQuot<U, Gcf<U, D>>: Unsigned + NonZero,
+    PartialQuot<D, Gcf<U, D>>: Unsigned + NonZero,
+{
+    type Output = Ratio<NInt<PartialQuot<U, Gcf<U, D>>>, PartialQuot<D, Gcf<U, D>>>;
+    #[inline]
+    fn private_reduce(self) -> Self::Output {
+        Ratio::new()
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Neg
+
+/// -(N / D) = -N / D
+impl<N, D> Neg for Ratio<N, D>
+where
+    N: Integer + Neg,
+    D: Unsigned + NonZero,
+    Negate<N>: Integer,
+{
+    type Output = Ratio<Negate<N>, D>;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Ratio {
+            n: -self.n,
+            d: self.d,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Abs
+
+/// |N / D| = |N| / D
+impl<N, D> Abs for Ratio<N, D>
+where
+    N: Integer + Abs,
+    D: Unsigned + NonZero,
+    AbsVal<N>: Integer,
+{
+    type Output = Ratio<AbsVal<N>, D>;
+}
+
+// ---------------------------------------------------------------------------------------
+// Add
+
+/// Nl / Dl + Nr / Dr = (Nl * Dr + Nr * Dl) / (Dl * Dr)
+impl<Nl, Dl, Nr, Dr> Add<Ratio<Nr, Dr>> for Ratio<Nl, Dl>
+where
+    Nl: Integer + Mul<PInt<Dr>>,
+    Dl: Unsigned + NonZero + Mul<Dr>,
+    Nr: Integer + Mul<PInt<Dl>>,
+    Dr: Unsigned + NonZero,
+    Prod<Nl, PInt<Dr>>: Add<Prod<Nr, PInt<Dl>>>,
+    Sum<Prod<Nl, PInt<Dr>>, Prod<Nr, PInt<Dl>>>: Integer,
+    Prod<Dl, Dr>: Unsigned + NonZero,
+    Ratio<Sum<Prod<Nl, PInt<Dr>>, Prod<Nr, PInt<Dl>>>, Prod<Dl, Dr>>: PrivateReduce,
+{
+    type Output =
+        PrivateReduceOut<Ratio<Sum<Prod<Nl, PInt<Dr>>, Prod<Nr, PInt<Dl>>>, Prod<Dl, Dr>>>;
+    #[inline]
+    fn add(self, rhs: Ratio<Nr, Dr>) -> Self::Output {
+        Ratio {
+            n: self.n * PInt { n: rhs.d } + rhs.n * PInt { n: self.d },
+            d: self.d * rhs.d,
+        }
+        .private_reduce()
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Sub
+
+/// Nl / Dl - Nr / Dr = (Nl * Dr - Nr * Dl) / (Dl * Dr)
+impl<Nl, Dl, Nr, Dr> Sub<Ratio<Nr, Dr>> for Ratio<Nl, Dl>
+where
+    Nl: Integer + Mul<PInt<Dr>>,
+    Dl: Unsigned + NonZero + Mul<Dr>,
+    Nr: Integer + Mul<PInt<Dl>>,
+    Dr: Unsigned + NonZero,
+    Prod<Nl, PInt<Dr>>: Sub<Prod<Nr, PInt<Dl>>>,
+    Diff<Prod<Nl, PInt<Dr>>, Prod<Nr, PInt<Dl>>>: Integer,
+    Prod<Dl, Dr>: Unsigned + NonZero,
+    Ratio<Diff<Prod<Nl, PInt<Dr>>, Prod<Nr, PInt<Dl>>>, Prod<Dl, Dr>>: PrivateReduce,
+{
+    type Output =
+        PrivateReduceOut<Ratio<Diff<Prod<Nl, PInt<Dr>>, Prod<Nr, PInt<Dl>>>, Prod<Dl, Dr>>>;
+    #[inline]
+    fn sub(self, rhs: Ratio<Nr, Dr>) -> Self::Output {
+        Ratio {
+            n: self.n * PInt { n: rhs.d } - rhs.n * PInt { n: self.d },
+            d: self.d * rhs.d,
+        }
+        .private_reduce()
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Mul
+
+/// (Nl / Dl) * (Nr / Dr) = (Nl * Nr) / (Dl * Dr)
+impl<Nl, Dl, Nr, Dr> Mul<Ratio<Nr, Dr>> for Ratio<Nl, Dl>
+where
+    Nl: Integer + Mul<Nr>,
+    Dl: Unsigned + NonZero + Mul<Dr>,
+    Nr: Integer,
+    Dr: Unsigned + NonZero,
+    Prod<Nl, Nr>: Integer,
+    Prod<Dl, Dr>: Unsigned + NonZero,
+    Ratio<Prod<Nl, Nr>, Prod<Dl, Dr>>: PrivateReduce,
+{
+    type Output = PrivateReduceOut<Ratio<Prod<Nl, Nr>, Prod<Dl, Dr>>>;
+    #[inline]
+    fn mul(self, rhs: Ratio<Nr, Dr>) -> Self::Output {
+        Ratio {
+            n: self.n * rhs.n,
+            d: self.d * rhs.d,
+        }
+        .private_reduce()
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Div
+
+/// (Nl / Dl) / (Ur / Dr) = (Nl * Dr) / (Dl * Ur)
+impl<Nl, Dl, Ur, Dr> Div<Ratio<PInt<Ur>, Dr>> for Ratio<Nl, Dl>
+where
+    Nl: Integer + Mul<PInt<Dr>>,
+    Dl: Unsigned + NonZero + Mul<Ur>,
+    Ur: Unsigned + NonZero,
+    Dr: Unsigned + NonZero,
+    Prod<Nl, PInt<Dr>>: Integer,
+    Prod<Dl, Ur>: Unsigned + NonZero,
+    Ratio<Prod<Nl, PInt<Dr>>, Prod<Dl, Ur>>: PrivateReduce,
+{
+    type Output = PrivateReduceOut<Ratio<Prod<Nl, PInt<Dr>>, Prod<Dl, Ur>>>;
+    #[inline]
+    fn div(self, rhs: Ratio<PInt<Ur>, Dr>) -> Self::Output {
+        Ratio {
+            n: self.n * PInt { n: rhs.d },
+            d: self.d * rhs.n.n,
+        }
+        .private_reduce()
+    }
+}
+
+/// (Nl / Dl) / (-Ur / Dr) = (Nl * -Dr) / (Dl * Ur)
+impl<Nl, Dl, Ur, Dr> Div<Ratio<NInt<Ur>, Dr>> for Ratio<Nl, Dl>
+where
+    Nl: Integer + Mul<NInt<Dr>>,
+    Dl: Unsigned + NonZero + Mul<Ur>,
+    Ur: Unsigned + NonZero,
+    Dr: Unsigned + NonZero,
+    Prod<Nl, NInt<Dr>>: Integer,
+    Prod<Dl, Ur>: Unsigned + NonZero,
+    Ratio<Prod<Nl, NInt<Dr>>, Prod<Dl, Ur>>: PrivateReduce,
+{
+    type Output = PrivateReduceOut<Ratio<Prod<Nl, NInt<Dr>>, Prod<Dl, Ur>>>;
+    #[inline]
+    fn div(self, rhs: Ratio<NInt<Ur>, Dr>) -> Self::Output {
+        Ratio {
+            n: self.n * NInt { n: rhs.d },
+            d: self.d * rhs.n.n,
+        }
+        .private_reduce()
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Cmp
+
+/// Nl / Dl <==> Nr / Dr if Nl * Dr <==> Nr * Dl
+impl<Nl, Dl, Nr, Dr> Cmp<Ratio<Nr, Dr>> for Ratio<Nl, Dl>
+where
+    Nl: Integer + Mul<PInt<Dr>>,
+    Dl: Unsigned + NonZero,
+    Nr: Integer + Mul<PInt<Dl>>,
+    Dr: Unsigned + NonZero,
+    Prod<Nl, PInt<Dr>>: Cmp<Prod<Nr, PInt<Dl>>>,
+{
+    type Output = Compare<Prod<Nl, PInt<Dr>>, Prod<Nr, PInt<Dl>>>;
+
+    #[inline]
+    fn compare<IM: InternalMarker>(&self, rhs: &Ratio<Nr, Dr>) -> Self::Output {
+        (self.n * PInt { n: rhs.d }).compare::<Internal>(&(rhs.n * PInt { n: self.d }))
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Min
+
+impl<N, D, Rhs> PrivateMin<Rhs, Less> for Ratio<N, D>
+where
+    N: Integer,
+    D: Unsigned + NonZero,
+{
+    type Output = Self;
+    #[inline]
+    fn private_min(self, _: Rhs) -> Self::Output {
+        self
+    }
+}
+
+impl<N, D, Rhs> PrivateMin<Rhs, Equal> for Ratio<N, D>
+where
+    N: Integer,
+    D: Unsigned + NonZero,
+{
+    type Output = Self;
+    #[inline]
+    fn private_min(self, _: Rhs) -> Self::Output {
+        self
+    }
+}
+
+impl<N, D, Rhs> PrivateMin<Rhs, Greater> for Ratio<N, D>
+where
+    N: Integer,
+    D: Unsigned + NonZero,
+{
+    type Output = Rhs;
+    #[inline]
+    fn private_min(self, rhs: Rhs) -> Self::Output {
+        rhs
+    }
+}
+
+impl<Nl, Dl, Nr, Dr> Min<Ratio<Nr, Dr>> for Ratio<Nl, Dl>
+where
+    Nl: Integer,
+    Dl: Unsigned + NonZero,
+    Nr: Integer,
+    Dr: Unsigned + NonZero,
+    Ratio<Nl, Dl>: Cmp<Ratio<Nr, Dr>> + PrivateMin<Ratio<Nr, Dr>, Compare<Self, Ratio<Nr, Dr>>>,
+{
+    type Output = PrivateMinOut<Self, Ratio<Nr, Dr>, Compare<Self, Ratio<Nr, Dr>>>;
+    #[inline]
+    fn min(self, rhs: Ratio<Nr, Dr>) -> Self::Output {
+        self.private_min(rhs)
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Max
+
+impl<N, D, Rhs> PrivateMax<Rhs, Less> for Ratio<N, D>
+where
+    N: Integer,
+    D: Unsigned + NonZero,
+{
+    type Output = Rhs;
+    #[inline]
+    fn private_max(self, rhs: Rhs) -> Self::Output {
+        rhs
+    }
+}
+
+impl<N, D, Rhs> PrivateMax<Rhs, Equal> for Ratio<N, D>
+where
+    N: Integer,
+    D: Unsigned + NonZero,
+{
+    type Output = Self;
+    #[inline]
+    fn private_max(self, _: Rhs) -> Self::Output {
+        self
+    }
+}
+
+impl<N, D, Rhs> PrivateMax<Rhs, Greater> for Ratio<N, D>
+where
+    N: Integer,
+    D: Unsigned + NonZero,
+{
+    type Output = Self;
+    #[inline]
+    fn private_max(self, _: Rhs) -> Self::Output {
+        self
+    }
+}
+
+impl<Nl, Dl, Nr, Dr> Max<Ratio<Nr, Dr>> for Ratio<Nl, Dl>
+where
+    Nl: Integer,
+    Dl: Unsigned + NonZero,
+    Nr: Integer,
+    Dr: Unsigned + NonZero,
+    Ratio<Nl, Dl>: Cmp<Ratio<Nr, Dr>> + PrivateMax<Ratio<Nr, Dr>, Compare<Self, Ratio<Nr, Dr>>>,
+{
+    type Output = PrivateMaxOut<Self, Ratio<Nr, Dr>, Compare<Self, Ratio<Nr, Dr>>>;
+    #[inline]
+    fn max(self, rhs: Ratio<Nr, Dr>) -> Self::Output {
+        self.private_max(rhs)
+    }
+}
+
+// ---------------------------------------------------------------------------------------
+// Pow
+
+/// (N / D)^0 = 1
+impl<N, D> Pow<Z0> for Ratio<N, D>
+where
+    N: Integer,
+    D: Unsigned + NonZero,
+{
+    type Output = Ratio<P1, U1>;
+    #[inline]
+    fn powi(self, _: Z0) -> Self::Output {
+        Ratio::new()
+    }
+}
+
+/// (N / D)^E = N^E / D^E
+impl<N, D, E> Pow<PInt<E>> for Ratio<N, D>
+where
+    N: Integer + Pow<PInt<E>>,
+    D: Unsigned + NonZero + Pow<E>,
+    E: Unsigned + NonZero,
+    Exp<N, PInt<E>>: Integer,
+    Exp<D, E>: Unsigned + NonZero,
+{
+    type Output = Ratio<Exp<N, PInt<E>>, Exp<D, E>>;
+    #[inline]
+    fn powi(self, _: PInt<E>) -> Self::Output {
+        Ratio::new()
+    }
+}
+
+/// (U / D)^-E = D^E / U^E
+impl<U, D, E> Pow<NInt<E>> for Ratio<PInt<U>, D>
+where
+    U: Unsigned + NonZero + Pow<E>,
+    D: Unsigned + NonZero + Pow<E>,
+    E: Unsigned + NonZero,
+    Exp<U, E>: Unsigned + NonZero,
+    Exp<D, E>: Unsigned + NonZero,
+{
+    type Output = Ratio<PInt<Exp<D, E>>, Exp<U, E>>;
+    #[inline]
+    fn powi(self, _: NInt<E>) -> Self::Output {
+        Ratio::new()
+    }
+}
+
+/// (-U / D)^-E = (-D)^E / U^E
+impl<U, D, E> Pow<NInt<E>> for Ratio<NInt<U>, D>
+where
+    U: Unsigned + NonZero + Pow<E>,
+    D: Unsigned + NonZero,
+    E: Unsigned + NonZero,
+    NInt<D>: Pow<PInt<E>>,
+    Exp<NInt<D>, PInt<E>>: Integer,
+    Exp<U, E>: Unsigned + NonZero,
+{
+    type Output = Ratio<Exp<NInt<D>, PInt<E>>, Exp<U, E>>;
+    #[inline]
+    fn powi(self, _: NInt<E>) -> Self::Output {
+        Ratio::new()
+    }
+}
+
+/// P^-E = 1 / P^E, for P > 1
+impl<U, Ba, Bb, E> Pow<NInt<E>> for PInt<UInt<UInt<U, Ba>, Bb>>
+where
+    U: Unsigned,
+    Ba: Bit,
+    Bb: Bit,
+    E: Unsigned + NonZero,
+    UInt<UInt<U, Ba>, Bb>: Pow<E>,
+    Exp<UInt<UInt<U, Ba>, Bb>, E>: Unsigned + NonZero,
+{
+    type Output = Ratio<P1, Exp<UInt<UInt<U, Ba>, Bb>, E>>;
+    #[inline]
+    fn powi(self, _: NInt<E>) -> Self::Output {
+        Ratio::new()
+    }
+}
+
+/// (-P)^-E = (-1)^E / P^E, for P > 1
+impl<U, Ba, Bb, E> Pow<NInt<E>> for NInt<UInt<UInt<U, Ba>, Bb>>
+where
+    U: Unsigned,
+    Ba: Bit,
+    Bb: Bit,
+    E: Unsigned + NonZero,
+    N1: Pow<PInt<E>>,
+    Exp<N1, PInt<E>>: Integer,
+    UInt<UInt<U, Ba>, Bb>: Pow<E>,
+    Exp<UInt<UInt<U, Ba>, Bb>, E>: Unsigned + NonZero,
+{
+    type Output = Ratio<Exp<N1, PInt<E>>, Exp<UInt<UInt<U, Ba>, Bb>, E>>;
+    #[inline]
+    fn powi(self, _: NInt<E>) -> Self::Output {
+        Ratio::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        assert_type_eq, consts::*, AbsVal, Compare, Diff, Equal, Exp, Greater, Less, Maximum,
+        Minimum, Negate, Prod, Quot, Ratio, Rational, Sum,
+    };
+
+    #[test]
+    fn ratio_arithmetic() {
+        assert_type_eq!(Sum<Ratio<P1, U2>, Ratio<P1, U3>>, Ratio<P5, U6>);
+        assert_type_eq!(Sum<Ratio<P1, U2>, Ratio<N1, U2>>, Ratio<Z0, U1>);
+        assert_type_eq!(Diff<Ratio<P1, U6>, Ratio<P1, U3>>, Ratio<N1, U6>);
+        assert_type_eq!(Prod<Ratio<P2, U3>, Ratio<P3, U4>>, Ratio<P1, U2>);
+        assert_type_eq!(Prod<Ratio<N4, U9>, Ratio<Z0, U1>>, Ratio<Z0, U1>);
+        assert_type_eq!(Quot<Ratio<P10, U1>, Ratio<P36, U1>>, Ratio<P5, U18>);
+        assert_type_eq!(Quot<Ratio<P1, U2>, Ratio<N3, U4>>, Ratio<N2, U3>);
+        assert_type_eq!(Negate<Ratio<P1, U2>>, Ratio<N1, U2>);
+        assert_type_eq!(AbsVal<Ratio<N1, U2>>, Ratio<P1, U2>);
+    }
+
+    #[test]
+    fn ratio_cmp() {
+        assert_type_eq!(Compare<Ratio<P1, U3>, Ratio<P1, U2>>, Less);
+        assert_type_eq!(Compare<Ratio<N1, U3>, Ratio<N1, U2>>, Greater);
+        assert_type_eq!(Compare<Ratio<P2, U4>, Ratio<P1, U2>>, Equal);
+        assert_type_eq!(Minimum<Ratio<P1, U3>, Ratio<N1, U2>>, Ratio<N1, U2>);
+        assert_type_eq!(Maximum<Ratio<P1, U3>, Ratio<N1, U2>>, Ratio<P1, U3>);
+    }
+
+    #[test]
+    fn ratio_pow() {
+        assert_type_eq!(Exp<Ratio<N2, U3>, P3>, Ratio<N8, U27>);
+        assert_type_eq!(Exp<Ratio<N2, U3>, N2>, Ratio<P9, U4>);
+        assert_type_eq!(Exp<Ratio<N2, U3>, N3>, Ratio<N27, U8>);
+        assert_type_eq!(Exp<Ratio<P2, U3>, Z0>, Ratio<P1, U1>);
+        assert_type_eq!(Exp<P2, N3>, Ratio<P1, U8>);
+        assert_type_eq!(Exp<N2, N3>, Ratio<N1, U8>);
+        assert_type_eq!(Exp<N3, N2>, Ratio<P1, U9>);
+    }
+
+    #[test]
+    fn ratio_to_float() {
+        assert_eq!(<Ratio<N3, U4> as Rational>::to_f64(), -0.75);
+        assert_eq!(<Ratio<P1, U8> as Rational>::to_f32(), 0.125);
+    }
+}
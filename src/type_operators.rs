@@ -307,6 +307,146 @@ pub trait Cmp<Rhs = Self> {
     fn compare<IM: InternalMarker>(&self, _: &Rhs) -> Self::Output;
 }
 
+/// A **type operator** that reverses the ordering `Self`, the type-level counterpart of
+/// `Ordering::reverse`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, Greater, Less, OrdReverse};
+///
+/// assert_type_eq!(<Less as OrdReverse>::Output, Greater);
+/// ```
+pub trait OrdReverse {
+    /// The reversed ordering.
+    type Output;
+}
+
+/// A **type operator** that chains two orderings, the type-level counterpart of
+/// `Ordering::then`: the result is `Self` unless it is `Equal`, in which case it is `Next`.
+///
+/// This compares composite keys lexicographically.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, Compare, Greater, Less, OrdThen, U1, U2, U3};
+///
+/// // (1, 3) > (1, 2)
+/// type Ordering = <Compare<U1, U1> as OrdThen<Compare<U3, U2>>>::Output;
+/// assert_type_eq!(Ordering, Greater);
+///
+/// // (1, 3) < (2, 2)
+/// type Ordering2 = <Compare<U1, U2> as OrdThen<Compare<U3, U2>>>::Output;
+/// assert_type_eq!(Ordering2, Less);
+/// ```
+pub trait OrdThen<Next> {
+    /// The chained ordering.
+    type Output;
+}
+
+/// A **type operator** that converts the ordering `Self` to an `Integer`: `N1` for `Less`, `Z0`
+/// for `Equal`, and `P1` for `Greater`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, Less, OrdToInt, N1};
+///
+/// assert_type_eq!(<Less as OrdToInt>::Output, N1);
+/// ```
+pub trait OrdToInt {
+    /// `N1`, `Z0`, or `P1`.
+    type Output;
+}
+
+/// A **type operator** that returns `True` if the ordering `Self` is `Less`, the type-level
+/// counterpart of `Ordering::is_lt`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Bit, Equal, Less, OrdIsLess};
+///
+/// assert!(<Less as OrdIsLess>::Output::to_bool());
+/// assert!(!<Equal as OrdIsLess>::Output::to_bool());
+/// ```
+pub trait OrdIsLess {
+    /// `True` or `False`.
+    type Output: Bit;
+}
+
+/// A **type operator** that returns `True` if the ordering `Self` is `Less` or `Equal`, the
+/// type-level counterpart of `Ordering::is_le`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Bit, Equal, Greater, OrdIsLessOrEqual};
+///
+/// assert!(<Equal as OrdIsLessOrEqual>::Output::to_bool());
+/// assert!(!<Greater as OrdIsLessOrEqual>::Output::to_bool());
+/// ```
+pub trait OrdIsLessOrEqual {
+    /// `True` or `False`.
+    type Output: Bit;
+}
+
+/// A **type operator** that returns `True` if the ordering `Self` is `Equal`, the type-level
+/// counterpart of `Ordering::is_eq`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Bit, Equal, Less, OrdIsEqual};
+///
+/// assert!(<Equal as OrdIsEqual>::Output::to_bool());
+/// assert!(!<Less as OrdIsEqual>::Output::to_bool());
+/// ```
+pub trait OrdIsEqual {
+    /// `True` or `False`.
+    type Output: Bit;
+}
+
+/// A **type operator** that returns `True` if the ordering `Self` is not `Equal`, the type-level
+/// counterpart of `Ordering::is_ne`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Bit, Equal, Greater, OrdIsNotEqual};
+///
+/// assert!(<Greater as OrdIsNotEqual>::Output::to_bool());
+/// assert!(!<Equal as OrdIsNotEqual>::Output::to_bool());
+/// ```
+pub trait OrdIsNotEqual {
+    /// `True` or `False`.
+    type Output: Bit;
+}
+
+/// A **type operator** that returns `True` if the ordering `Self` is `Greater`, the type-level
+/// counterpart of `Ordering::is_gt`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Bit, Greater, Less, OrdIsGreater};
+///
+/// assert!(<Greater as OrdIsGreater>::Output::to_bool());
+/// assert!(!<Less as OrdIsGreater>::Output::to_bool());
+/// ```
+pub trait OrdIsGreater {
+    /// `True` or `False`.
+    type Output: Bit;
+}
+
+/// A **type operator** that returns `True` if the ordering `Self` is `Greater` or `Equal`, the
+/// type-level counterpart of `Ordering::is_ge`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Bit, Greater, Less, OrdIsGreaterOrEqual};
+///
+/// assert!(<Greater as OrdIsGreaterOrEqual>::Output::to_bool());
+/// assert!(!<Less as OrdIsGreaterOrEqual>::Output::to_bool());
+/// ```
+pub trait OrdIsGreaterOrEqual {
+    /// `True` or `False`.
+    type Output: Bit;
+}
+
 /// A **type operator** that gives the length of an `Array` or the number of bits in a `UInt`.
 pub trait Len {
     /// The length as a type-level unsigned integer.
@@ -324,6 +464,58 @@ pub trait PartialDiv<Rhs = Self> {
     fn partial_div(self, _: Rhs) -> Self::Output;
 }
 
+/// Division rounding toward negative infinity. For `Unsigned` this is the same as `Div`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{DivFloor, Integer, N7, P2};
+///
+/// assert_eq!(<N7 as DivFloor<P2>>::Output::to_i32(), -4);
+/// ```
+pub trait DivFloor<Rhs = Self> {
+    /// The type of the result of the division
+    type Output;
+}
+
+/// Division rounding toward positive infinity.
+///
+/// # Example
+/// ```rust
+/// use typenum::{DivCeil, Unsigned, U100, U64};
+///
+/// assert_eq!(<U100 as DivCeil<U64>>::Output::to_u32(), 2);
+/// ```
+pub trait DivCeil<Rhs = Self> {
+    /// The type of the result of the division
+    type Output;
+}
+
+/// Euclidean division, the quotient `q` such that `Self = q * Rhs + r` with `0 <= r < |Rhs|`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{DivEuclid, Integer, N2, N7};
+///
+/// assert_eq!(<N7 as DivEuclid<N2>>::Output::to_i32(), 4);
+/// ```
+pub trait DivEuclid<Rhs = Self> {
+    /// The type of the result of the division
+    type Output;
+}
+
+/// The remainder of Euclidean division, which is never negative.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Integer, RemEuclid, N2, N7};
+///
+/// assert_eq!(<N7 as RemEuclid<N2>>::Output::to_i32(), 1);
+/// ```
+pub trait RemEuclid<Rhs = Self> {
+    /// The type of the remainder
+    type Output;
+}
+
 /// A **type operator** that returns the minimum of `Self` and `Rhs`.
 pub trait Min<Rhs = Self> {
     /// The type of the minimum of `Self` and `Rhs`
@@ -340,6 +532,61 @@ pub trait Max<Rhs = Self> {
     fn max(self, rhs: Rhs) -> Self::Output;
 }
 
+/// A **type operator** for subtraction that floors at zero instead of failing to compile.
+///
+/// # Example
+/// ```rust
+/// use typenum::{SaturatingSub, Unsigned, U3, U5};
+///
+/// assert_eq!(<U5 as SaturatingSub<U3>>::Output::to_u32(), 2);
+/// assert_eq!(<U3 as SaturatingSub<U5>>::Output::to_u32(), 0);
+/// ```
+pub trait SaturatingSub<Rhs = Self> {
+    /// The type of the result of the subtraction
+    type Output;
+}
+
+/// A **type operator** that returns the absolute difference of `Self` and `Rhs`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{AbsDiff, Integer, N2, P3};
+///
+/// assert_eq!(<N2 as AbsDiff<P3>>::Output::to_i32(), 5);
+/// ```
+pub trait AbsDiff<Rhs = Self> {
+    /// The type of `|Self - Rhs|`
+    type Output;
+}
+
+/// A **type operator** that restricts `Self` to the range `[Lo, Hi]`. It is only implemented
+/// when `Lo <= Hi`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, op, Clamp, Integer, N3, N7, P2, P5};
+///
+/// assert_eq!(<N7 as Clamp<N3, P5>>::Output::to_i32(), -3);
+/// assert_eq!(<P2 as Clamp<N3, P5>>::Output::to_i32(), 2);
+///
+/// // In `op!`, the value comes first and the bounds follow.
+/// assert_type_eq!(op!(clamp(N7, N3, P5)), N3);
+/// ```
+pub trait Clamp<Lo, Hi> {
+    /// The type of the clamped value
+    type Output;
+}
+
+use crate::{Maximum, Minimum, True};
+impl<N, Lo, Hi> Clamp<Lo, Hi> for N
+where
+    Lo: IsLessOrEqual<Hi, Output = True>,
+    N: Max<Lo>,
+    Maximum<N, Lo>: Min<Hi>,
+{
+    type Output = Minimum<Maximum<N, Lo>, Hi>;
+}
+
 use crate::Compare;
 
 /// A **type operator** that returns `True` if `Self < Rhs`, otherwise returns `False`.
@@ -553,6 +800,84 @@ pub trait SquareRoot {
     type Output;
 }
 
+/// A **type operator** for taking the integer `K`th root of `Self`.
+///
+/// The integer `K`th root of `n` is the largest integer `m` such that `n >= m^K`. It is only
+/// implemented for `K >= 1`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{NthRoot, Unsigned, U100, U4};
+///
+/// assert_eq!(<U100 as NthRoot<U4>>::Output::to_u32(), 3);
+/// ```
+pub trait NthRoot<K> {
+    /// The result of the integer root.
+    type Output;
+}
+
+/// A **type operator** for taking the integer cube root of `Self`. This is `NthRoot<U3>`.
+pub trait CubeRoot {
+    /// The result of the integer cube root.
+    type Output;
+}
+
+/// A **type operator** for taking the ceiling of the `K`th root of `Self`.
+///
+/// This is the smallest integer `m` such that `m^K >= n`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{CeilNthRoot, Unsigned, U3, U9};
+///
+/// assert_eq!(<U9 as CeilNthRoot<U3>>::Output::to_u32(), 3);
+/// ```
+pub trait CeilNthRoot<K> {
+    /// The result of the ceiling root.
+    type Output;
+}
+
+/// A **type operator** for taking the ceiling of the square root of `Self`.
+pub trait CeilSquareRoot {
+    /// The result of the ceiling square root.
+    type Output;
+}
+
+/// A **type operator** for taking the ceiling of the cube root of `Self`.
+pub trait CeilCubeRoot {
+    /// The result of the ceiling cube root.
+    type Output;
+}
+
+/// A **type operator** that returns `True` if `Self` is the square of an integer, otherwise
+/// returns `False`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Bit, IsPerfectSquare, U48, U49};
+///
+/// assert!(<U49 as IsPerfectSquare>::Output::to_bool());
+/// assert!(!<U48 as IsPerfectSquare>::Output::to_bool());
+/// ```
+pub trait IsPerfectSquare {
+    /// `True` or `False`.
+    type Output: Bit;
+}
+
+/// A **type operator** that returns `True` if `Self` is the `K`th power of an integer, otherwise
+/// returns `False`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Bit, IsPerfectPower, U27, U3};
+///
+/// assert!(<U27 as IsPerfectPower<U3>>::Output::to_bool());
+/// ```
+pub trait IsPerfectPower<K> {
+    /// `True` or `False`.
+    type Output: Bit;
+}
+
 /// A **type operator** for taking the integer binary logarithm of `Self`.
 ///
 /// The integer binary logarighm of `n` is the largest integer `m` such
@@ -563,6 +888,53 @@ pub trait Logarithm2 {
     type Output;
 }
 
+/// A **type operator** for taking the ceiling of the binary logarithm of `Self`.
+///
+/// This is the smallest integer `m` such that `2^m >= n`. It is not implemented for `0`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{CeilLogarithm2, Unsigned, U9};
+///
+/// assert_eq!(<U9 as CeilLogarithm2>::Output::to_u32(), 4);
+/// ```
+pub trait CeilLogarithm2 {
+    /// The result of the ceiling binary logarithm.
+    type Output;
+}
+
+/// A **type operator** for taking the integer logarithm of `Self` to the base `Base`.
+///
+/// This is the largest integer `m` such that `n >= Base^m`, or `floor(log_Base(n))`. It is only
+/// implemented for `Self >= 1` and `Base >= 2`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Logarithm, Unsigned, U10, U999};
+///
+/// assert_eq!(<U999 as Logarithm<U10>>::Output::to_u32(), 2);
+/// ```
+pub trait Logarithm<Base> {
+    /// The result of the integer logarithm.
+    type Output;
+}
+
+/// A **type operator** for taking the ceiling of the logarithm of `Self` to the base `Base`.
+///
+/// This is the smallest integer `m` such that `Base^m >= n`. It is only implemented for
+/// `Self >= 1` and `Base >= 2`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{CeilLogarithm, Unsigned, U10, U101};
+///
+/// assert_eq!(<U101 as CeilLogarithm<U10>>::Output::to_u32(), 3);
+/// ```
+pub trait CeilLogarithm<Base> {
+    /// The result of the ceiling logarithm.
+    type Output;
+}
+
 /// A **type operator** that computes the [greatest common divisor][gcd] of `Self` and `Rhs`.
 ///
 /// [gcd]: https://en.wikipedia.org/wiki/Greatest_common_divisor
@@ -579,6 +951,803 @@ pub trait Gcd<Rhs> {
     type Output;
 }
 
+/// A **type operator** that computes the [least common multiple][lcm] of `Self` and `Rhs`.
+///
+/// The least common multiple of zero and anything is zero.
+///
+/// [lcm]: https://en.wikipedia.org/wiki/Least_common_multiple
+///
+/// # Example
+///
+/// ```rust
+/// use typenum::{Lcm, Unsigned, U12, U8};
+///
+/// assert_eq!(<U12 as Lcm<U8>>::Output::to_i32(), 24);
+/// ```
+pub trait Lcm<Rhs> {
+    /// The least common multiple.
+    type Output;
+}
+
+/// A **type operator** that runs the [extended Euclidean algorithm][egcd] on `Self` and `Rhs`.
+///
+/// The output is the type array `[G, X, Y]`, where `G` is the non-negative greatest common
+/// divisor and the Bezout coefficients `X` and `Y` are signed integers with
+/// `Self * X + Rhs * Y = G`.
+///
+/// [egcd]: https://en.wikipedia.org/wiki/Extended_Euclidean_algorithm
+///
+/// # Example
+///
+/// ```rust
+/// use typenum::{assert_type_eq, tarr, ExtendedGcd, N2, P1, P3, P7};
+///
+/// assert_type_eq!(<P3 as ExtendedGcd<P7>>::Output, tarr![P1, N2, P1]);
+/// ```
+pub trait ExtendedGcd<Rhs> {
+    /// The greatest common divisor and the Bezout coefficients.
+    type Output;
+}
+
+/// A **type operator** that computes the [factorial][fact] of `Self`.
+///
+/// [fact]: https://en.wikipedia.org/wiki/Factorial
+///
+/// # Example
+/// ```rust
+/// use typenum::{Factorial, Unsigned, U5};
+///
+/// assert_eq!(<U5 as Factorial>::Output::to_u32(), 120);
+/// ```
+pub trait Factorial {
+    /// The factorial.
+    type Output;
+}
+
+/// A **type operator** that computes the [binomial coefficient][binom], the number of ways to
+/// choose `K` items out of `Self`.
+///
+/// It is zero when `K > Self`.
+///
+/// [binom]: https://en.wikipedia.org/wiki/Binomial_coefficient
+///
+/// # Example
+/// ```rust
+/// use typenum::{Binomial, Unsigned, U16, U4};
+///
+/// assert_eq!(<U16 as Binomial<U4>>::Output::to_u32(), 1820);
+/// ```
+pub trait Binomial<K> {
+    /// The binomial coefficient.
+    type Output;
+}
+
+/// A **type operator** that computes the number of [`K`-permutations][perm] of `Self` items,
+/// `Self! / (Self - K)!`.
+///
+/// It is zero when `K > Self`.
+///
+/// [perm]: https://en.wikipedia.org/wiki/Permutation#k-permutations_of_n
+///
+/// # Example
+/// ```rust
+/// use typenum::{Permutations, Unsigned, U3, U5};
+///
+/// assert_eq!(<U5 as Permutations<U3>>::Output::to_u32(), 60);
+/// ```
+pub trait Permutations<K> {
+    /// The number of permutations.
+    type Output;
+}
+
+/// A **type operator** that returns `True` if `Self` is prime, otherwise returns `False`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Bit, IsPrime, U91, U97};
+///
+/// assert!(<U97 as IsPrime>::Output::to_bool());
+/// assert!(!<U91 as IsPrime>::Output::to_bool());
+/// ```
+pub trait IsPrime {
+    /// `True` or `False`.
+    type Output: Bit;
+}
+
+/// A **type operator** that gives the prime factors of `Self` as a type-level array, in ascending
+/// order and with repeats. The array for `1` is empty, and it is not implemented for `0`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, tarr, Factorize, U2, U3, U5, U60};
+///
+/// assert_type_eq!(<U60 as Factorize>::Output, tarr![U2, U2, U3, U5]);
+/// ```
+pub trait Factorize {
+    /// The array of prime factors.
+    type Output;
+}
+
+/// A **type operator** that computes [Euler's totient function][phi] of `Self`, the number of
+/// integers in `1..=Self` that are coprime to `Self`. It is not implemented for `0`.
+///
+/// [phi]: https://en.wikipedia.org/wiki/Euler%27s_totient_function
+///
+/// # Example
+/// ```rust
+/// use typenum::{Totient, Unsigned, U36};
+///
+/// assert_eq!(<U36 as Totient>::Output::to_u32(), 12);
+/// ```
+pub trait Totient {
+    /// The totient.
+    type Output;
+}
+
+/// A **type operator** that gives all the divisors of `Self` as a type-level array, in ascending
+/// order. It is not implemented for `0`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, tarr, Divisors, U1, U2, U3, U4, U6, U12};
+///
+/// assert_type_eq!(<U12 as Divisors>::Output, tarr![U1, U2, U3, U4, U6, U12]);
+/// ```
+pub trait Divisors {
+    /// The array of divisors.
+    type Output;
+}
+
+/// A **type operator** that counts the divisors of `Self`. It is not implemented for `0`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{DivisorCount, Unsigned, U12};
+///
+/// assert_eq!(<U12 as DivisorCount>::Output::to_u32(), 6);
+/// ```
+pub trait DivisorCount {
+    /// The number of divisors.
+    type Output;
+}
+
+/// A **type operator** that sums the divisors of `Self`. It is not implemented for `0`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{DivisorSum, Unsigned, U12};
+///
+/// assert_eq!(<U12 as DivisorSum>::Output::to_u32(), 28);
+/// ```
+pub trait DivisorSum {
+    /// The sum of the divisors.
+    type Output;
+}
+
+/// A **type operator** for addition modulo `M`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{ModAdd, Unsigned, U5, U6, U7};
+///
+/// assert_eq!(<U5 as ModAdd<U6, U7>>::Output::to_u32(), 4);
+/// ```
+pub trait ModAdd<Rhs, M> {
+    /// `(Self + Rhs) % M`
+    type Output;
+}
+
+/// A **type operator** for multiplication modulo `M`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{ModMul, Unsigned, U5, U6, U7};
+///
+/// assert_eq!(<U5 as ModMul<U6, U7>>::Output::to_u32(), 2);
+/// ```
+pub trait ModMul<Rhs, M> {
+    /// `(Self * Rhs) % M`
+    type Output;
+}
+
+/// A **type operator** for exponentiation modulo `M`, by repeated squaring.
+///
+/// Every intermediate result is reduced modulo `M`, so this works for exponents where `Pow`
+/// would not.
+///
+/// # Example
+/// ```rust
+/// use typenum::{ModPow, Unsigned, U1000, U13, U3};
+///
+/// assert_eq!(<U3 as ModPow<U1000, U13>>::Output::to_u32(), 3);
+/// ```
+pub trait ModPow<Exp, M> {
+    /// `Self^Exp % M`
+    type Output;
+}
+
+/// A **type operator** for the inverse of `Self` modulo `M`, the `x` in `0..M` such that
+/// `Self * x % M == 1 % M`. It is only implemented when `Self` and `M` are coprime.
+///
+/// # Example
+/// ```rust
+/// use typenum::{ModInverse, Unsigned, U3, U7};
+///
+/// assert_eq!(<U3 as ModInverse<U7>>::Output::to_u32(), 5);
+/// ```
+pub trait ModInverse<M> {
+    /// The inverse.
+    type Output;
+}
+
+/// A **type operator** for the logical NAND of two bits, `!(Self & Rhs)`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, Nand, B0, B1};
+///
+/// assert_type_eq!(<B1 as Nand<B1>>::Output, B0);
+/// assert_type_eq!(<B1 as Nand<B0>>::Output, B1);
+/// ```
+pub trait Nand<Rhs = Self> {
+    /// The result of the NAND.
+    type Output: Bit;
+}
+
+/// A **type operator** for the logical NOR of two bits, `!(Self | Rhs)`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, Nor, B0, B1};
+///
+/// assert_type_eq!(<B0 as Nor<B0>>::Output, B1);
+/// assert_type_eq!(<B0 as Nor<B1>>::Output, B0);
+/// ```
+pub trait Nor<Rhs = Self> {
+    /// The result of the NOR.
+    type Output: Bit;
+}
+
+/// A **type operator** for the logical XNOR of two bits, `!(Self ^ Rhs)`, which is `B1` if the
+/// bits are equal.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, Xnor, B0, B1};
+///
+/// assert_type_eq!(<B0 as Xnor<B0>>::Output, B1);
+/// assert_type_eq!(<B0 as Xnor<B1>>::Output, B0);
+/// ```
+pub trait Xnor<Rhs = Self> {
+    /// The result of the XNOR.
+    type Output: Bit;
+}
+
+/// A **type operator** for the logical implication `Self -> Rhs`, `!Self | Rhs`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, Implies, B0, B1};
+///
+/// assert_type_eq!(<B0 as Implies<B0>>::Output, B1);
+/// assert_type_eq!(<B1 as Implies<B0>>::Output, B0);
+/// ```
+pub trait Implies<Rhs> {
+    /// The result of the implication.
+    type Output: Bit;
+}
+
+/// A **type operator** for a [full adder][adder], which adds the bits `Self`, `Rhs` and
+/// `CarryIn`.
+///
+/// [adder]: https://en.wikipedia.org/wiki/Adder_(electronics)#Full_adder
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, FullAdd, B0, B1};
+///
+/// assert_type_eq!(<B1 as FullAdd<B1, B1>>::Output, B1);
+/// assert_type_eq!(<B1 as FullAdd<B1, B1>>::Carry, B1);
+/// assert_type_eq!(<B1 as FullAdd<B0, B1>>::Output, B0);
+/// ```
+pub trait FullAdd<Rhs, CarryIn> {
+    /// The sum bit.
+    type Output: Bit;
+    /// The carry out.
+    type Carry: Bit;
+}
+
+/// A **type operator** for a [full subtractor][subtractor], which computes
+/// `Self - Rhs - BorrowIn` on bits.
+///
+/// [subtractor]: https://en.wikipedia.org/wiki/Subtractor#Full_subtractor
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, FullSub, B0, B1};
+///
+/// assert_type_eq!(<B0 as FullSub<B1, B0>>::Output, B1);
+/// assert_type_eq!(<B0 as FullSub<B1, B0>>::Borrow, B1);
+/// assert_type_eq!(<B1 as FullSub<B0, B1>>::Borrow, B0);
+/// ```
+pub trait FullSub<Rhs, BorrowIn> {
+    /// The difference bit.
+    type Output: Bit;
+    /// The borrow out.
+    type Borrow: Bit;
+}
+
+/// A **type operator** that selects `Then` if `Self` is `B1` and `Else` if it is `B0`.
+///
+/// This is the type-level counterpart of `if`, and of `Bit::select`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, If, Le, U2, U3};
+///
+/// type Min = <Le<U2, U3> as If<U2, U3>>::Output;
+/// assert_type_eq!(Min, U2);
+/// ```
+pub trait If<Then, Else> {
+    /// `Then` or `Else`.
+    type Output;
+}
+
+/// A **type operator** that converts an `Unsigned` to the `Integer` with the same value.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, ToSigned, P5, U0, U5, Z0};
+///
+/// assert_type_eq!(<U5 as ToSigned>::Output, P5);
+/// assert_type_eq!(<U0 as ToSigned>::Output, Z0);
+/// ```
+pub trait ToSigned {
+    /// The signed integer.
+    type Output;
+}
+
+/// A **type operator** that converts a non-negative `Integer` to the `Unsigned` with the same
+/// value.
+///
+/// It is not implemented for negative integers.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, ToUnsigned, P5, U0, U5, Z0};
+///
+/// assert_type_eq!(<P5 as ToUnsigned>::Output, U5);
+/// assert_type_eq!(<Z0 as ToUnsigned>::Output, U0);
+/// ```
+pub trait ToUnsigned {
+    /// The unsigned integer.
+    type Output;
+}
+
+/// A **type operator** that converts a `Bit` to the `Unsigned` `U0` or `U1`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, BitToUnsigned, B1, U1};
+///
+/// assert_type_eq!(<B1 as BitToUnsigned>::Output, U1);
+/// ```
+pub trait BitToUnsigned {
+    /// `U0` or `U1`.
+    type Output;
+}
+
+/// A **type operator** that converts a `Bit` to the `Integer` `Z0` or `P1`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, BitToInteger, B1, P1};
+///
+/// assert_type_eq!(<B1 as BitToInteger>::Output, P1);
+/// ```
+pub trait BitToInteger {
+    /// `Z0` or `P1`.
+    type Output;
+}
+
+/// A **type operator** that returns the sign of `Self` as an `Integer`: `N1` if it is negative,
+/// `Z0` if it is zero, and `P1` if it is positive.
+///
+/// # Example
+/// ```rust
+/// use typenum::{assert_type_eq, Signum, N1, N3, P1, U0, U5, Z0};
+///
+/// assert_type_eq!(<N3 as Signum>::Output, N1);
+/// assert_type_eq!(<U0 as Signum>::Output, Z0);
+/// assert_type_eq!(<U5 as Signum>::Output, P1);
+/// ```
+pub trait Signum {
+    /// `N1`, `Z0`, or `P1`.
+    type Output;
+}
+
+/// A **type operator** that returns `True` if `Self` is less than zero, otherwise returns `False`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Bit, IsNegative, N3, U3};
+///
+/// assert!(<N3 as IsNegative>::Output::to_bool());
+/// assert!(!<U3 as IsNegative>::Output::to_bool());
+/// ```
+pub trait IsNegative {
+    /// `True` or `False`.
+    type Output: Bit;
+}
+
+/// A **type operator** that returns `True` if `Self` is greater than zero, otherwise returns
+/// `False`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Bit, IsPositive, P3, Z0};
+///
+/// assert!(<P3 as IsPositive>::Output::to_bool());
+/// assert!(!<Z0 as IsPositive>::Output::to_bool());
+/// ```
+pub trait IsPositive {
+    /// `True` or `False`.
+    type Output: Bit;
+}
+
+/// A **type operator** that returns `True` if `Self` is zero, otherwise returns `False`.
+///
+/// Unlike the `Zero` marker trait, this is implemented for every number, so it can be used to
+/// branch at the type level.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Bit, IsZero, B1, U0};
+///
+/// assert!(<U0 as IsZero>::Output::to_bool());
+/// assert!(!<B1 as IsZero>::Output::to_bool());
+/// ```
+pub trait IsZero {
+    /// `True` or `False`.
+    type Output: Bit;
+}
+
+/// A **type operator** that returns `True` if `Self` is even, otherwise returns `False`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Bit, IsEven, N4, U7};
+///
+/// assert!(<N4 as IsEven>::Output::to_bool());
+/// assert!(!<U7 as IsEven>::Output::to_bool());
+/// ```
+pub trait IsEven {
+    /// `True` or `False`.
+    type Output: Bit;
+}
+
+/// A **type operator** that returns `True` if `Self` is odd, otherwise returns `False`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Bit, IsOdd, N3, U8};
+///
+/// assert!(<N3 as IsOdd>::Output::to_bool());
+/// assert!(!<U8 as IsOdd>::Output::to_bool());
+/// ```
+pub trait IsOdd {
+    /// `True` or `False`.
+    type Output: Bit;
+}
+
+/// A **type operator** for addition modulo `2^W`, like `wrapping_add` on a `W`-bit integer.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Unsigned, WrappingAdd, U10, U100, U1000};
+///
+/// // A 10-bit counter
+/// assert_eq!(<U1000 as WrappingAdd<U100, U10>>::Output::to_u32(), 76);
+/// ```
+pub trait WrappingAdd<Rhs, W> {
+    /// The wrapped sum.
+    type Output;
+}
+
+/// A **type operator** for subtraction modulo `2^W`, like `wrapping_sub` on a `W`-bit integer.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Unsigned, WrappingSub, U1, U2, U8};
+///
+/// assert_eq!(<U1 as WrappingSub<U2, U8>>::Output::to_u32(), 255);
+/// ```
+pub trait WrappingSub<Rhs, W> {
+    /// The wrapped difference.
+    type Output;
+}
+
+/// A **type operator** for multiplication modulo `2^W`, like `wrapping_mul` on a `W`-bit integer.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Unsigned, WrappingMul, U100, U3, U8};
+///
+/// assert_eq!(<U100 as WrappingMul<U3, U8>>::Output::to_u32(), 44);
+/// ```
+pub trait WrappingMul<Rhs, W> {
+    /// The wrapped product.
+    type Output;
+}
+
+/// A **type operator** for negation modulo `2^W`, like `wrapping_neg` on a `W`-bit integer.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Unsigned, WrappingNeg, U1, U8};
+///
+/// assert_eq!(<U1 as WrappingNeg<U8>>::Output::to_u32(), 255);
+/// ```
+pub trait WrappingNeg<W> {
+    /// The wrapped negation.
+    type Output;
+}
+
+/// A **type operator** for the bitwise not of the low `W` bits of `Self`, like `!` on a `W`-bit
+/// integer.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Unsigned, WrappingNot, U16, U255};
+///
+/// assert_eq!(<U255 as WrappingNot<U16>>::Output::to_u32(), 0xff00);
+/// ```
+pub trait WrappingNot<W> {
+    /// The complemented bits.
+    type Output;
+}
+
+/// A **type operator** that encodes the `Integer` `Self` as a `W`-bit [two's complement][tc]
+/// number, given as an `Unsigned`.
+///
+/// It is not implemented if `Self` does not fit in `W` bits.
+///
+/// [tc]: https://en.wikipedia.org/wiki/Two%27s_complement
+///
+/// # Example
+/// ```rust
+/// use typenum::{ToTwosComplement, Unsigned, N1, N128, P5, U8};
+///
+/// assert_eq!(<P5 as ToTwosComplement<U8>>::Output::to_u32(), 5);
+/// assert_eq!(<N1 as ToTwosComplement<U8>>::Output::to_u32(), 0xff);
+/// assert_eq!(<N128 as ToTwosComplement<U8>>::Output::to_u32(), 0x80);
+/// ```
+pub trait ToTwosComplement<W> {
+    /// The encoded number.
+    type Output;
+}
+
+/// A **type operator** that decodes the `W`-bit [two's complement][tc] number `Self` into an
+/// `Integer`.
+///
+/// It is not implemented if `Self` does not fit in `W` bits.
+///
+/// [tc]: https://en.wikipedia.org/wiki/Two%27s_complement
+///
+/// # Example
+/// ```rust
+/// use typenum::{FromTwosComplement, Integer, U255, U5, U8};
+///
+/// assert_eq!(<U5 as FromTwosComplement<U8>>::Output::to_i32(), 5);
+/// assert_eq!(<U255 as FromTwosComplement<U8>>::Output::to_i32(), -1);
+/// ```
+pub trait FromTwosComplement<W> {
+    /// The decoded integer.
+    type Output;
+}
+
+/// A **type operator** that returns `True` if `Self` is a power of two, otherwise returns `False`.
+///
+/// Unlike the `PowerOfTwo` marker trait, this is implemented for every `Unsigned`, so it can be
+/// used to branch at the type level.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Bit, IsPowerOfTwo, U6, U8};
+///
+/// assert!(<U8 as IsPowerOfTwo>::Output::to_bool());
+/// assert!(!<U6 as IsPowerOfTwo>::Output::to_bool());
+/// ```
+pub trait IsPowerOfTwo {
+    /// `True` or `False`.
+    type Output: Bit;
+}
+
+/// A **type operator** that counts the ones in the binary representation of `Self`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{CountOnes, Unsigned, U13};
+///
+/// assert_eq!(<U13 as CountOnes>::Output::to_u32(), 3);
+/// ```
+pub trait CountOnes {
+    /// The number of ones.
+    type Output;
+}
+
+/// A **type operator** that counts the zeros in the binary representation of `Self`, as a
+/// number `Width` bits wide. It is only implemented when `Self` fits in `Width` bits.
+///
+/// # Example
+/// ```rust
+/// use typenum::{CountZeros, Unsigned, U13, U8};
+///
+/// assert_eq!(<U13 as CountZeros<U8>>::Output::to_u32(), 5);
+/// ```
+pub trait CountZeros<Width> {
+    /// The number of zeros.
+    type Output;
+}
+
+/// A **type operator** that counts the zeros below the lowest one in the binary representation
+/// of `Self`. It is not implemented for `0`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{TrailingZeros, Unsigned, U12};
+///
+/// assert_eq!(<U12 as TrailingZeros>::Output::to_u32(), 2);
+/// ```
+pub trait TrailingZeros {
+    /// The number of trailing zeros.
+    type Output;
+}
+
+/// A **type operator** that counts the zeros above the highest one in the binary representation
+/// of `Self`, as a number `Width` bits wide. It is only implemented when `Self` fits in `Width`
+/// bits.
+///
+/// # Example
+/// ```rust
+/// use typenum::{LeadingZeros, Unsigned, U12, U8};
+///
+/// assert_eq!(<U12 as LeadingZeros<U8>>::Output::to_u32(), 4);
+/// ```
+pub trait LeadingZeros<Width> {
+    /// The number of leading zeros.
+    type Output;
+}
+
+/// A **type operator** that returns `True` if `Self` has an odd number of ones in its binary
+/// representation, otherwise returns `False`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{Bit, Parity, U13};
+///
+/// assert!(<U13 as Parity>::Output::to_bool());
+/// ```
+pub trait Parity {
+    /// `True` or `False`.
+    type Output: Bit;
+}
+
+/// A **type operator** for the smallest power of two greater than or equal to `Self`.
+///
+/// As with `u32::next_power_of_two`, the next power of two of `0` is `1`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{NextPowerOfTwo, Unsigned, U5};
+///
+/// assert_eq!(<U5 as NextPowerOfTwo>::Output::to_u32(), 8);
+/// ```
+pub trait NextPowerOfTwo {
+    /// The next power of two.
+    type Output;
+}
+
+/// A **type operator** for the largest power of two less than or equal to `Self`. It is not
+/// implemented for `0`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{PrevPowerOfTwo, Unsigned, U5};
+///
+/// assert_eq!(<U5 as PrevPowerOfTwo>::Output::to_u32(), 4);
+/// ```
+pub trait PrevPowerOfTwo {
+    /// The previous power of two.
+    type Output;
+}
+
+/// A **type operator** that rounds `Self` up to a multiple of `Align`, which must be a power
+/// of two.
+///
+/// # Example
+/// ```rust
+/// use typenum::{AlignUp, Unsigned, U1000, U256};
+///
+/// assert_eq!(<U1000 as AlignUp<U256>>::Output::to_u32(), 1024);
+/// ```
+pub trait AlignUp<Align> {
+    /// `Self` rounded up.
+    type Output;
+}
+
+/// A **type operator** that rounds `Self` down to a multiple of `Align`, which must be a power
+/// of two.
+///
+/// # Example
+/// ```rust
+/// use typenum::{AlignDown, Unsigned, U1000, U256};
+///
+/// assert_eq!(<U1000 as AlignDown<U256>>::Output::to_u32(), 768);
+/// ```
+pub trait AlignDown<Align> {
+    /// `Self` rounded down.
+    type Output;
+}
+
+/// A **type operator** for subtraction that gives `TNone` instead of failing to compile when
+/// the result cannot be represented, and `TSome` of the difference otherwise.
+///
+/// # Example
+/// ```rust
+/// use typenum::{CheckedSub, TNone, TSome, Same, U2, U3, U5};
+///
+/// type A = <U5 as CheckedSub<U3>>::Output;
+/// type B = <U3 as CheckedSub<U5>>::Output;
+/// let _: <A as Same<TSome<U2>>>::Output;
+/// let _: <B as Same<TNone>>::Output;
+/// ```
+pub trait CheckedSub<Rhs = Self> {
+    /// The difference, wrapped in `TSome`, or `TNone`.
+    type Output;
+}
+
+/// A **type operator** for division that gives `TNone` when dividing by zero, and `TSome` of
+/// the quotient otherwise.
+pub trait CheckedDiv<Rhs = Self> {
+    /// The quotient, wrapped in `TSome`, or `TNone`.
+    type Output;
+}
+
+/// A **type operator** for the remainder that gives `TNone` when dividing by zero, and `TSome`
+/// of the remainder otherwise.
+pub trait CheckedRem<Rhs = Self> {
+    /// The remainder, wrapped in `TSome`, or `TNone`.
+    type Output;
+}
+
+/// A **type operator** for partial division that gives `TNone` when dividing by zero or when
+/// `Rhs` does not divide `Self`, and `TSome` of the quotient otherwise.
+pub trait CheckedPartialDiv<Rhs = Self> {
+    /// The quotient, wrapped in `TSome`, or `TNone`.
+    type Output;
+}
+
+/// A **type operator** that gives `True` for `TSome` and `False` for `TNone`.
+pub trait IsSome {
+    /// `True` or `False`.
+    type Output: Bit;
+}
+
+/// A **type operator** that unwraps a `TSome`, or gives `Fallback` for `TNone`.
+///
+/// # Example
+/// ```rust
+/// use typenum::{CheckedDiv, Same, Unsigned, UnwrapOr, U0, U1, U6};
+///
+/// type Q = <<U6 as CheckedDiv<U0>>::Output as UnwrapOr<U1>>::Output;
+/// assert_eq!(<Q as Same<U1>>::Output::to_u32(), 1);
+/// ```
+pub trait UnwrapOr<Fallback> {
+    /// The wrapped type, or `Fallback`.
+    type Output;
+}
+
 /// A **type operator** for taking a concrete integer value from a type.
 ///
 /// It returns arbitrary integer value without explicitly specifying the
@@ -35,9 +35,9 @@ use crate::{
         PrivateCmpOut, PrivateLogarithm2, PrivatePow, PrivatePowOut, PrivateSquareRoot, PrivateSub,
         PrivateSubOut, PrivateXor, PrivateXorOut, Trim, TrimOut,
     },
-    Add1, Cmp, Double, Equal, Gcd, Gcf, GrEq, Greater, IsGreaterOrEqual, Len, Length, Less, Log2,
-    Logarithm2, Maximum, Minimum, NonZero, Or, Ord, Pow, Prod, Shleft, Shright, Sqrt, Square,
-    SquareRoot, Sub1, Sum, ToInt, Zero,
+    Add1, Cmp, Double, Equal, Gcd, Gcf, GrEq, Greater, IsGreaterOrEqual, Lcm, Len, Length, Less,
+    Log2, Logarithm2, Maximum, Minimum, NonZero, Or, Ord, PartialQuot, Pow, Prod, Shleft, Shright,
+    Sqrt, Square, SquareRoot, Sub1, Sum, ToInt, Zero,
 };
 use core::ops::{Add, BitAnd, BitOr, BitXor, Mul, Shl, Shr, Sub};
 
@@ -1430,6 +1430,503 @@ where
         Gcf<Diff<Maximum<Odd<Xp>, Odd<Yp>>, Minimum<Odd<Xp>, Odd<Yp>>>, Minimum<Odd<Xp>, Odd<Yp>>>;
 }
 
+//------------------------------------------
+// Least Common Multiple
+
+/// lcm(0, 0) = 0
+impl Lcm<U0> for U0 {
+    type Output = U0;
+}
+
+/// lcm(x, 0) = 0
+impl<U: Unsigned, B: Bit> Lcm<U0> for UInt<U, B> {
+    type Output = U0;
+}
+
+/// lcm(0, y) = 0
+impl<U: Unsigned, B: Bit> Lcm<UInt<U, B>> for U0 {
+    type Output = U0;
+}
+
+/// lcm(x, y) = (x / gcd(x, y)) * y
+impl<Ul, Bl, Ur, Br> Lcm<UInt<Ur, Br>> for UInt<Ul, Bl>
+where
+    UInt<Ul, Bl>: Gcd<UInt<Ur, Br>> + PartialDiv<Gcf<UInt<Ul, Bl>, UInt<Ur, Br>>>,
+    PartialQuot<UInt<Ul, Bl>, Gcf<UInt<Ul, Bl>, UInt<Ur, Br>>>: Mul<UInt<Ur, Br>>,
+{
+    type Output = Prod<PartialQuot<UInt<Ul, Bl>, Gcf<UInt<Ul, Bl>, UInt<Ur, Br>>>, UInt<Ur, Br>>;
+}
+
+//------------------------------------------
+// Factorial
+use crate::{Fact, Factorial};
+
+/// 0! = 1
+impl Factorial for UTerm {
+    type Output = U1;
+}
+
+/// n! = n * (n - 1)!
+impl<U, B> Factorial for UInt<U, B>
+where
+    U: Unsigned,
+    B: Bit,
+    UInt<U, B>: Sub<B1>,
+    Sub1<UInt<U, B>>: Factorial,
+    UInt<U, B>: Mul<Fact<Sub1<UInt<U, B>>>>,
+{
+    type Output = Prod<UInt<U, B>, Fact<Sub1<UInt<U, B>>>>;
+}
+
+//------------------------------------------
+// Permutations
+use crate::{Perm, Permutations};
+
+/// perm(n, 0) = 1
+impl<N: Unsigned> Permutations<U0> for N {
+    type Output = U1;
+}
+
+/// perm(0, k) = 0 for k > 0
+impl<U: Unsigned, B: Bit> Permutations<UInt<U, B>> for U0 {
+    type Output = U0;
+}
+
+/// perm(n, k) = n * perm(n - 1, k - 1)
+impl<Un, Bn, Uk, Bk> Permutations<UInt<Uk, Bk>> for UInt<Un, Bn>
+where
+    Un: Unsigned,
+    Bn: Bit,
+    Uk: Unsigned,
+    Bk: Bit,
+    UInt<Un, Bn>: Sub<B1>,
+    UInt<Uk, Bk>: Sub<B1>,
+    Sub1<UInt<Un, Bn>>: Permutations<Sub1<UInt<Uk, Bk>>>,
+    UInt<Un, Bn>: Mul<Perm<Sub1<UInt<Un, Bn>>, Sub1<UInt<Uk, Bk>>>>,
+{
+    type Output = Prod<UInt<Un, Bn>, Perm<Sub1<UInt<Un, Bn>>, Sub1<UInt<Uk, Bk>>>>;
+}
+
+//------------------------------------------
+// Binomial
+use crate::Binomial;
+
+/// choose(n, k) = perm(n, k) / k!
+impl<N, K> Binomial<K> for N
+where
+    N: Unsigned + Permutations<K>,
+    K: Unsigned + Factorial,
+    Perm<N, K>: PartialDiv<Fact<K>>,
+{
+    type Output = PartialQuot<Perm<N, K>, Fact<K>>;
+}
+
+//------------------------------------------
+// IsPrime
+use crate::private::{PrivateIsPrime, PrivateIsPrimeOut};
+use crate::{
+    consts::{U2, U4},
+    Gr, IsGreater, IsPrime, Mod,
+};
+
+/// 0 is not prime
+impl IsPrime for UTerm {
+    type Output = B0;
+}
+
+/// 1 is not prime
+impl IsPrime for UInt<UTerm, B1> {
+    type Output = B0;
+}
+
+/// Trial division by 2, 3, 4, ... for Self >= 2
+impl<U, Bu, B> IsPrime for UInt<UInt<U, Bu>, B>
+where
+    U: Unsigned,
+    Bu: Bit,
+    B: Bit,
+    U4: IsGreater<Self>,
+    Self: Rem<U2> + PrivateIsPrime<U2, Gr<U4, Self>, Mod<Self, U2>>,
+    PrivateIsPrimeOut<Self, U2, Gr<U4, Self>, Mod<Self, U2>>: Bit,
+{
+    type Output = PrivateIsPrimeOut<Self, U2, Gr<U4, Self>, Mod<Self, U2>>;
+}
+
+/// No divisor up to sqrt(N) was found
+impl<N, D, R> PrivateIsPrime<D, B1, R> for N {
+    type Output = B1;
+}
+
+/// D divides N
+impl<N, D> PrivateIsPrime<D, B0, U0> for N {
+    type Output = B0;
+}
+
+/// D does not divide N, so try D + 1
+impl<N, D, U, B> PrivateIsPrime<D, B0, UInt<U, B>> for N
+where
+    D: Add<B1>,
+    Add1<D>: Mul,
+    Square<Add1<D>>: IsGreater<N>,
+    N: Rem<Add1<D>> + PrivateIsPrime<Add1<D>, Gr<Square<Add1<D>>, N>, Mod<N, Add1<D>>>,
+{
+    type Output = PrivateIsPrimeOut<N, Add1<D>, Gr<Square<Add1<D>>, N>, Mod<N, Add1<D>>>;
+}
+
+//------------------------------------------
+// Factorize
+use crate::private::{
+    PrivateFactorize, PrivateFactorizeFrom, PrivateFactorizeFromOut, PrivateFactorizeOut,
+};
+use crate::{ATerm, Factorize, TArr};
+
+impl<U, B> Factorize for UInt<U, B>
+where
+    U: Unsigned,
+    B: Bit,
+    Self: PrivateFactorizeFrom<U2>,
+{
+    type Output = PrivateFactorizeFromOut<Self, U2>;
+}
+
+/// 1 has no prime factors
+impl<D> PrivateFactorizeFrom<D> for UInt<UTerm, B1> {
+    type Output = ATerm;
+}
+
+/// Trial division by D, D + 1, ... for Self >= 2
+impl<U, Bu, B, D> PrivateFactorizeFrom<D> for UInt<UInt<U, Bu>, B>
+where
+    U: Unsigned,
+    Bu: Bit,
+    B: Bit,
+    D: Mul,
+    Square<D>: IsGreater<Self>,
+    Self: Rem<D> + PrivateFactorize<D, Gr<Square<D>, Self>, Mod<Self, D>>,
+{
+    type Output = PrivateFactorizeOut<Self, D, Gr<Square<D>, Self>, Mod<Self, D>>;
+}
+
+/// No divisor up to sqrt(N) was found, so N is prime
+impl<N, D, R> PrivateFactorize<D, B1, R> for N {
+    type Output = TArr<N, ATerm>;
+}
+
+/// D divides N, so it is the smallest prime factor
+impl<N, D> PrivateFactorize<D, B0, U0> for N
+where
+    N: Div<D>,
+    Quot<N, D>: PrivateFactorizeFrom<D>,
+{
+    type Output = TArr<D, PrivateFactorizeFromOut<Quot<N, D>, D>>;
+}
+
+/// D does not divide N, so try D + 1
+impl<N, D, U, B> PrivateFactorize<D, B0, UInt<U, B>> for N
+where
+    D: Add<B1>,
+    N: PrivateFactorizeFrom<Add1<D>>,
+{
+    type Output = PrivateFactorizeFromOut<N, Add1<D>>;
+}
+
+//------------------------------------------
+// Totient
+use crate::private::{PrivateTotient, PrivateTotientOut};
+use crate::{Factors, Totient};
+
+/// phi(n) is the product of p^(k - 1) * (p - 1) over the prime powers p^k dividing n
+impl<U, B> Totient for UInt<U, B>
+where
+    U: Unsigned,
+    B: Bit,
+    UInt<U, B>: Factorize,
+    Factors<UInt<U, B>>: PrivateTotient<U0>,
+{
+    type Output = PrivateTotientOut<Factors<UInt<U, B>>, U0>;
+}
+
+impl<Prev> PrivateTotient<Prev> for ATerm {
+    type Output = U1;
+}
+
+/// Each factor contributes p if it repeats the previous factor, and p - 1 otherwise
+impl<P, A, Prev> PrivateTotient<Prev> for TArr<P, A>
+where
+    P: Sub<B1> + IsEqual<Prev>,
+    Sub1<P>: Add<Eq<P, Prev>>,
+    A: PrivateTotient<P>,
+    Sum<Sub1<P>, Eq<P, Prev>>: Mul<PrivateTotientOut<A, P>>,
+{
+    type Output = Prod<Sum<Sub1<P>, Eq<P, Prev>>, PrivateTotientOut<A, P>>;
+}
+
+//------------------------------------------
+// Divisors
+use crate::private::{
+    PrivateAppend, PrivateAppendOut, PrivateDivisors, PrivateDivisorsFrom, PrivateDivisorsFromOut,
+    PrivateDivisorsOut,
+};
+use crate::{DivisorList, Divisors};
+
+impl<U, B> Divisors for UInt<U, B>
+where
+    U: Unsigned,
+    B: Bit,
+    UInt<U, B>: PrivateDivisorsFrom<U1>,
+{
+    type Output = PrivateDivisorsFromOut<UInt<U, B>, U1>;
+}
+
+impl<N, D> PrivateDivisorsFrom<D> for N
+where
+    D: Mul,
+    Square<D>: Cmp<N>,
+    N: Rem<D> + PrivateDivisors<D, Compare<Square<D>, N>, Mod<N, D>>,
+{
+    type Output = PrivateDivisorsOut<N, D, Compare<Square<D>, N>, Mod<N, D>>;
+}
+
+/// D > sqrt(N), so all divisors have been found
+impl<N, D, R> PrivateDivisors<D, Greater, R> for N {
+    type Output = ATerm;
+}
+
+/// D = sqrt(N) is the middle divisor
+impl<N, D, R> PrivateDivisors<D, Equal, R> for N {
+    type Output = TArr<D, ATerm>;
+}
+
+/// D divides N, so D goes before the rest of the divisors and N / D goes after them
+impl<N, D> PrivateDivisors<D, Less, U0> for N
+where
+    D: Add<B1>,
+    N: Div<D> + PrivateDivisorsFrom<Add1<D>>,
+    PrivateDivisorsFromOut<N, Add1<D>>: PrivateAppend<Quot<N, D>>,
+{
+    type Output = TArr<D, PrivateAppendOut<PrivateDivisorsFromOut<N, Add1<D>>, Quot<N, D>>>;
+}
+
+/// D does not divide N, so try D + 1
+impl<N, D, U, B> PrivateDivisors<D, Less, UInt<U, B>> for N
+where
+    D: Add<B1>,
+    N: PrivateDivisorsFrom<Add1<D>>,
+{
+    type Output = PrivateDivisorsFromOut<N, Add1<D>>;
+}
+
+//------------------------------------------
+// DivisorCount
+use crate::DivisorCount;
+
+impl<U, B> DivisorCount for UInt<U, B>
+where
+    U: Unsigned,
+    B: Bit,
+    UInt<U, B>: Divisors,
+    DivisorList<UInt<U, B>>: Len,
+{
+    type Output = Length<DivisorList<UInt<U, B>>>;
+}
+
+//------------------------------------------
+// DivisorSum
+use crate::private::{PrivateArraySum, PrivateArraySumOut};
+use crate::DivisorSum;
+
+impl<U, B> DivisorSum for UInt<U, B>
+where
+    U: Unsigned,
+    B: Bit,
+    UInt<U, B>: Divisors,
+    DivisorList<UInt<U, B>>: PrivateArraySum,
+{
+    type Output = PrivateArraySumOut<DivisorList<UInt<U, B>>>;
+}
+
+//------------------------------------------
+// ModAdd
+use crate::{ModAdd, ModExp, ModInverse, ModMul, ModPow, ModProd};
+
+/// ((A % M) + (B % M)) % M
+impl<A, B, M> ModAdd<B, M> for A
+where
+    A: Unsigned + Rem<M>,
+    B: Unsigned + Rem<M>,
+    M: Unsigned + NonZero,
+    Mod<A, M>: Add<Mod<B, M>>,
+    Sum<Mod<A, M>, Mod<B, M>>: Rem<M>,
+{
+    type Output = Mod<Sum<Mod<A, M>, Mod<B, M>>, M>;
+}
+
+//------------------------------------------
+// ModMul
+
+/// ((A % M) * (B % M)) % M
+impl<A, B, M> ModMul<B, M> for A
+where
+    A: Unsigned + Rem<M>,
+    B: Unsigned + Rem<M>,
+    M: Unsigned + NonZero,
+    Mod<A, M>: Mul<Mod<B, M>>,
+    Prod<Mod<A, M>, Mod<B, M>>: Rem<M>,
+{
+    type Output = Mod<Prod<Mod<A, M>, Mod<B, M>>, M>;
+}
+
+//------------------------------------------
+// ModPow
+
+/// A^0 % M = 1 % M
+impl<A, M> ModPow<U0, M> for A
+where
+    A: Unsigned,
+    M: Unsigned + NonZero,
+    U1: Rem<M>,
+{
+    type Output = Mod<U1, M>;
+}
+
+/// A^(2e) % M = (A^2 % M)^e % M
+impl<A, Ue, M> ModPow<UInt<Ue, B0>, M> for A
+where
+    A: ModMul<A, M>,
+    ModProd<A, A, M>: ModPow<Ue, M>,
+{
+    type Output = ModExp<ModProd<A, A, M>, Ue, M>;
+}
+
+/// A^(2e + 1) % M = A * (A^2 % M)^e % M
+impl<A, Ue, M> ModPow<UInt<Ue, B1>, M> for A
+where
+    A: ModMul<A, M>,
+    ModProd<A, A, M>: ModPow<Ue, M>,
+    A: ModMul<ModExp<ModProd<A, A, M>, Ue, M>, M>,
+{
+    type Output = ModProd<A, ModExp<ModProd<A, A, M>, Ue, M>, M>;
+}
+
+//------------------------------------------
+// ModInverse
+use crate::private::{PrivateModInverse, PrivateModInverseOut};
+
+/// The extended Euclidean algorithm, starting from the remainders A and M, with coefficients of
+/// A of 1 and 0
+impl<A, M> ModInverse<M> for A
+where
+    A: Unsigned + PrivateModInverse<M, M, U1, U0>,
+    M: Unsigned + NonZero,
+{
+    type Output = PrivateModInverseOut<A, M, M, U1, U0>;
+}
+
+/// The last nonzero remainder is gcd(A, M), so the inverse only exists if it is 1
+impl<M, OldS, S> PrivateModInverse<M, U0, OldS, S> for U1
+where
+    OldS: Rem<M>,
+{
+    type Output = Mod<OldS, M>;
+}
+
+/// With Q = OldR / R, the next remainder is OldR % R and the next coefficient is
+/// OldS - Q * S, computed as (OldS + M - (Q * S) % M) % M
+impl<OldR, M, Ur, Br, OldS, S> PrivateModInverse<M, UInt<Ur, Br>, OldS, S> for OldR
+where
+    OldR: Div<UInt<Ur, Br>> + Rem<UInt<Ur, Br>>,
+    Quot<OldR, UInt<Ur, Br>>: Mul<S>,
+    Prod<Quot<OldR, UInt<Ur, Br>>, S>: Rem<M>,
+    OldS: Add<M>,
+    Sum<OldS, M>: Sub<Mod<Prod<Quot<OldR, UInt<Ur, Br>>, S>, M>>,
+    Diff<Sum<OldS, M>, Mod<Prod<Quot<OldR, UInt<Ur, Br>>, S>, M>>: Rem<M>,
+    UInt<Ur, Br>: PrivateModInverse<
+        M,
+        Mod<OldR, UInt<Ur, Br>>,
+        S,
+        Mod<Diff<Sum<OldS, M>, Mod<Prod<Quot<OldR, UInt<Ur, Br>>, S>, M>>, M>,
+    >,
+{
+    type Output = PrivateModInverseOut<
+        UInt<Ur, Br>,
+        M,
+        Mod<OldR, UInt<Ur, Br>>,
+        S,
+        Mod<Diff<Sum<OldS, M>, Mod<Prod<Quot<OldR, UInt<Ur, Br>>, S>, M>>, M>,
+    >;
+}
+
+//------------------------------------------
+// Wrapping arithmetic
+use crate::{WrappingAdd, WrappingMul, WrappingNeg, WrappingNot, WrappingSub};
+
+/// `2^W - 1`, whose bitwise and with a number is that number modulo `2^W`
+type Mask<W> = Sub1<Shleft<U1, W>>;
+
+/// (A + B) & Mask
+impl<A, B, W> WrappingAdd<B, W> for A
+where
+    A: Unsigned + Add<B>,
+    B: Unsigned,
+    W: Unsigned,
+    U1: Shl<W>,
+    Shleft<U1, W>: Sub<B1>,
+    Sum<A, B>: BitAnd<Mask<W>>,
+{
+    type Output = And<Sum<A, B>, Mask<W>>;
+}
+
+/// (A + 2^W - (B & Mask)) & Mask
+impl<A, B, W> WrappingSub<B, W> for A
+where
+    A: Unsigned + Add<Shleft<U1, W>>,
+    B: Unsigned + BitAnd<Mask<W>>,
+    W: Unsigned,
+    U1: Shl<W>,
+    Shleft<U1, W>: Sub<B1>,
+    Sum<A, Shleft<U1, W>>: Sub<And<B, Mask<W>>>,
+    Diff<Sum<A, Shleft<U1, W>>, And<B, Mask<W>>>: BitAnd<Mask<W>>,
+{
+    type Output = And<Diff<Sum<A, Shleft<U1, W>>, And<B, Mask<W>>>, Mask<W>>;
+}
+
+/// (A * B) & Mask
+impl<A, B, W> WrappingMul<B, W> for A
+where
+    A: Unsigned + Mul<B>,
+    B: Unsigned,
+    W: Unsigned,
+    U1: Shl<W>,
+    Shleft<U1, W>: Sub<B1>,
+    Prod<A, B>: BitAnd<Mask<W>>,
+{
+    type Output = And<Prod<A, B>, Mask<W>>;
+}
+
+/// (2^W - (A & Mask)) & Mask
+impl<A, W> WrappingNeg<W> for A
+where
+    A: Unsigned + BitAnd<Mask<W>>,
+    W: Unsigned,
+    U1: Shl<W>,
+    Shleft<U1, W>: Sub<B1> + Sub<And<A, Mask<W>>>,
+    Diff<Shleft<U1, W>, And<A, Mask<W>>>: BitAnd<Mask<W>>,
+{
+    type Output = And<Diff<Shleft<U1, W>, And<A, Mask<W>>>, Mask<W>>;
+}
+
+/// (A & Mask) ^ Mask
+impl<A, W> WrappingNot<W> for A
+where
+    A: Unsigned + BitAnd<Mask<W>>,
+    W: Unsigned,
+    U1: Shl<W>,
+    Shleft<U1, W>: Sub<B1>,
+    And<A, Mask<W>>: BitXor<Mask<W>>,
+{
+    type Output = Xor<And<A, Mask<W>>, Mask<W>>;
+}
+
 #[cfg(test)]
 mod gcd_tests {
     use super::*;
@@ -2064,6 +2561,38 @@ where
     }
 }
 
+// -----------------------------------------
+// DivFloor
+use crate::DivFloor;
+impl<Ur: Unsigned, Br: Bit> DivFloor<UInt<Ur, Br>> for UTerm {
+    type Output = UTerm;
+}
+
+/// `M / N`, rounded down
+impl<Ul: Unsigned, Bl: Bit, Ur: Unsigned, Br: Bit> DivFloor<UInt<Ur, Br>> for UInt<Ul, Bl>
+where
+    UInt<Ul, Bl>: Div<UInt<Ur, Br>>,
+{
+    type Output = Quot<UInt<Ul, Bl>, UInt<Ur, Br>>;
+}
+
+// -----------------------------------------
+// DivCeil
+use crate::DivCeil;
+impl<Ur: Unsigned, Br: Bit> DivCeil<UInt<Ur, Br>> for UTerm {
+    type Output = UTerm;
+}
+
+/// `M / N`, rounded up: `(M + N - 1) / N`
+impl<Ul: Unsigned, Bl: Bit, Ur: Unsigned, Br: Bit> DivCeil<UInt<Ur, Br>> for UInt<Ul, Bl>
+where
+    UInt<Ur, Br>: Sub<B1>,
+    UInt<Ul, Bl>: Add<Sub1<UInt<Ur, Br>>>,
+    Sum<UInt<Ul, Bl>, Sub1<UInt<Ur, Br>>>: Div<UInt<Ur, Br>>,
+{
+    type Output = Quot<Sum<UInt<Ul, Bl>, Sub1<UInt<Ur, Br>>>, UInt<Ur, Br>>;
+}
+
 // -----------------------------------------
 // PrivateMin
 use crate::private::{PrivateMin, PrivateMinOut};
@@ -2208,6 +2737,51 @@ where
     }
 }
 
+// -----------------------------------------
+// SaturatingSub
+use crate::SaturatingSub;
+
+impl<U> SaturatingSub<U> for UTerm
+where
+    U: Unsigned,
+{
+    type Output = UTerm;
+}
+
+/// `UInt - U` is `U0` if `U > UInt`
+impl<U, B, Ur> SaturatingSub<Ur> for UInt<U, B>
+where
+    U: Unsigned,
+    B: Bit,
+    Ur: Unsigned,
+    UInt<U, B>: Max<Ur>,
+    Maximum<UInt<U, B>, Ur>: Sub<Ur>,
+{
+    type Output = Diff<Maximum<UInt<U, B>, Ur>, Ur>;
+}
+
+// -----------------------------------------
+// AbsDiff
+use crate::AbsDiff;
+
+impl<U> AbsDiff<U> for UTerm
+where
+    U: Unsigned,
+{
+    type Output = U;
+}
+
+impl<U, B, Ur> AbsDiff<Ur> for UInt<U, B>
+where
+    U: Unsigned,
+    B: Bit,
+    Ur: Unsigned,
+    UInt<U, B>: Max<Ur> + Min<Ur>,
+    Maximum<UInt<U, B>, Ur>: Sub<Minimum<UInt<U, B>, Ur>>,
+{
+    type Output = Diff<Maximum<UInt<U, B>, Ur>, Minimum<UInt<U, B>, Ur>>;
+}
+
 // -----------------------------------------
 // SquareRoot
 
@@ -2293,6 +2867,106 @@ fn sqrt_test() {
     // ...
 }
 
+// -----------------------------------------
+// NthRoot
+use crate::{Exp, NthRoot, Root};
+
+// root(0) = 0.
+impl<K: Unsigned + NonZero> NthRoot<K> for UTerm {
+    type Output = UTerm;
+}
+
+// General case of root(Self) where Self >= 1, the same guess and check as for `SquareRoot`. If
+// r = root(Self >> K), then root(Self) is either 2*r or 2*r+1, so compare (2*r+1)^K against
+// Self and add the resulting bit to 2*r.
+impl<U, B, K> NthRoot<K> for UInt<U, B>
+where
+    U: Unsigned,
+    B: Bit,
+    K: Unsigned + NonZero,
+    Self: Shr<K>,
+    Shright<Self, K>: NthRoot<K>,
+    Root<Shright<Self, K>, K>: Shl<B1>,
+    Double<Root<Shright<Self, K>, K>>: Add<B1>,
+    Add1<Double<Root<Shright<Self, K>, K>>>: Pow<K>,
+    Self: IsGreaterOrEqual<Exp<Add1<Double<Root<Shright<Self, K>, K>>>, K>>,
+    Double<Root<Shright<Self, K>, K>>:
+        Add<GrEq<Self, Exp<Add1<Double<Root<Shright<Self, K>, K>>>, K>>>,
+{
+    type Output = Sum<
+        Double<Root<Shright<Self, K>, K>>,
+        GrEq<Self, Exp<Add1<Double<Root<Shright<Self, K>, K>>>, K>>,
+    >;
+}
+
+// -----------------------------------------
+// CubeRoot
+use crate::{consts::U3, CubeRoot};
+
+impl<N> CubeRoot for N
+where
+    N: Unsigned + NthRoot<U3>,
+{
+    type Output = Root<N, U3>;
+}
+
+// -----------------------------------------
+// CeilNthRoot
+use crate::{CeilCubeRoot, CeilNthRoot, CeilSquareRoot, IsLess, Le};
+
+// root(N) + (root(N)^K < N)
+impl<N, K> CeilNthRoot<K> for N
+where
+    N: Unsigned + NthRoot<K>,
+    Root<N, K>: Pow<K>,
+    Exp<Root<N, K>, K>: IsLess<N>,
+    Root<N, K>: Add<Le<Exp<Root<N, K>, K>, N>>,
+{
+    type Output = Sum<Root<N, K>, Le<Exp<Root<N, K>, K>, N>>;
+}
+
+impl<N> CeilSquareRoot for N
+where
+    N: Unsigned + SquareRoot,
+    Sqrt<N>: Mul,
+    Square<Sqrt<N>>: IsLess<N>,
+    Sqrt<N>: Add<Le<Square<Sqrt<N>>, N>>,
+{
+    type Output = Sum<Sqrt<N>, Le<Square<Sqrt<N>>, N>>;
+}
+
+impl<N> CeilCubeRoot for N
+where
+    N: Unsigned + CeilNthRoot<U3>,
+{
+    type Output = <N as CeilNthRoot<U3>>::Output;
+}
+
+// -----------------------------------------
+// IsPerfectSquare
+use crate::{Eq, IsEqual, IsPerfectPower, IsPerfectSquare};
+
+impl<N> IsPerfectSquare for N
+where
+    N: Unsigned + SquareRoot,
+    Sqrt<N>: Mul,
+    Square<Sqrt<N>>: IsEqual<N>,
+{
+    type Output = Eq<Square<Sqrt<N>>, N>;
+}
+
+// -----------------------------------------
+// IsPerfectPower
+
+impl<N, K> IsPerfectPower<K> for N
+where
+    N: Unsigned + NthRoot<K>,
+    Root<N, K>: Pow<K>,
+    Exp<Root<N, K>, K>: IsEqual<N>,
+{
+    type Output = Eq<Exp<Root<N, K>, K>, N>;
+}
+
 // -----------------------------------------
 // Logarithm2
 
@@ -2318,6 +2992,326 @@ where
     type Output = Add1<Log2<U>>;
 }
 
+// -----------------------------------------
+// CeilLogarithm2
+use crate::CeilLogarithm2;
+
+// ceil(log2(1)) = 0.
+impl CeilLogarithm2 for UInt<UTerm, B1> {
+    type Output = U0;
+}
+
+// General case where Self >= 2: log2(Self - 1) + 1
+impl<U, Bu, B> CeilLogarithm2 for UInt<UInt<U, Bu>, B>
+where
+    U: Unsigned,
+    Bu: Bit,
+    B: Bit,
+    UInt<UInt<U, Bu>, B>: Sub<B1>,
+    Sub1<UInt<UInt<U, Bu>, B>>: Logarithm2,
+    Log2<Sub1<UInt<UInt<U, Bu>, B>>>: Add<B1>,
+{
+    type Output = Add1<Log2<Sub1<UInt<UInt<U, Bu>, B>>>>;
+}
+
+// -----------------------------------------
+// Logarithm
+use crate::private::{PrivateLogarithm, PrivateLogarithmOut};
+use crate::{Log, Logarithm};
+
+// log(N) = 0 if N < Base
+impl<N, Base> PrivateLogarithm<Base, Less> for N {
+    type Output = U0;
+}
+
+// log(N) = log(N / Base) + 1 if N >= Base
+impl<N, Base> PrivateLogarithm<Base, Equal> for N
+where
+    N: Div<Base>,
+    Quot<N, Base>: Logarithm<Base>,
+    Log<Quot<N, Base>, Base>: Add<B1>,
+{
+    type Output = Add1<Log<Quot<N, Base>, Base>>;
+}
+
+impl<N, Base> PrivateLogarithm<Base, Greater> for N
+where
+    N: Div<Base>,
+    Quot<N, Base>: Logarithm<Base>,
+    Log<Quot<N, Base>, Base>: Add<B1>,
+{
+    type Output = Add1<Log<Quot<N, Base>, Base>>;
+}
+
+// The base must be at least 2.
+impl<U, B, Ub, Bb, Bc> Logarithm<UInt<UInt<Ub, Bb>, Bc>> for UInt<U, B>
+where
+    U: Unsigned,
+    B: Bit,
+    Ub: Unsigned,
+    Bb: Bit,
+    Bc: Bit,
+    UInt<U, B>: Cmp<UInt<UInt<Ub, Bb>, Bc>>
+        + PrivateLogarithm<UInt<UInt<Ub, Bb>, Bc>, Compare<UInt<U, B>, UInt<UInt<Ub, Bb>, Bc>>>,
+{
+    type Output = PrivateLogarithmOut<
+        UInt<U, B>,
+        UInt<UInt<Ub, Bb>, Bc>,
+        Compare<UInt<U, B>, UInt<UInt<Ub, Bb>, Bc>>,
+    >;
+}
+
+// -----------------------------------------
+// CeilLogarithm
+use crate::CeilLogarithm;
+
+// ceil(log(1)) = 0.
+impl<Ub, Bb, Bc> CeilLogarithm<UInt<UInt<Ub, Bb>, Bc>> for UInt<UTerm, B1>
+where
+    Ub: Unsigned,
+    Bb: Bit,
+    Bc: Bit,
+{
+    type Output = U0;
+}
+
+// General case where Self >= 2: log(Self - 1) + 1
+impl<U, Bu, B, Base> CeilLogarithm<Base> for UInt<UInt<U, Bu>, B>
+where
+    U: Unsigned,
+    Bu: Bit,
+    B: Bit,
+    UInt<UInt<U, Bu>, B>: Sub<B1>,
+    Sub1<UInt<UInt<U, Bu>, B>>: Logarithm<Base>,
+    Log<Sub1<UInt<UInt<U, Bu>, B>>, Base>: Add<B1>,
+{
+    type Output = Add1<Log<Sub1<UInt<UInt<U, Bu>, B>>, Base>>;
+}
+
+// -----------------------------------------
+// Sign and parity predicates
+use crate::{
+    consts::{P1, Z0},
+    IsEven, IsNegative, IsOdd, IsPositive, IsZero, Signum,
+};
+
+impl Signum for UTerm {
+    type Output = Z0;
+}
+
+impl<U: Unsigned, B: Bit> Signum for UInt<U, B> {
+    type Output = P1;
+}
+
+impl IsNegative for UTerm {
+    type Output = B0;
+}
+
+impl<U: Unsigned, B: Bit> IsNegative for UInt<U, B> {
+    type Output = B0;
+}
+
+impl IsPositive for UTerm {
+    type Output = B0;
+}
+
+impl<U: Unsigned, B: Bit> IsPositive for UInt<U, B> {
+    type Output = B1;
+}
+
+impl IsZero for UTerm {
+    type Output = B1;
+}
+
+impl<U: Unsigned, B: Bit> IsZero for UInt<U, B> {
+    type Output = B0;
+}
+
+impl IsEven for UTerm {
+    type Output = B1;
+}
+
+impl<U: Unsigned> IsEven for UInt<U, B0> {
+    type Output = B1;
+}
+
+impl<U: Unsigned> IsEven for UInt<U, B1> {
+    type Output = B0;
+}
+
+impl IsOdd for UTerm {
+    type Output = B0;
+}
+
+impl<U: Unsigned, B: Bit> IsOdd for UInt<U, B> {
+    type Output = B;
+}
+
+// -----------------------------------------
+// IsPowerOfTwo
+use crate::IsPowerOfTwo;
+
+impl IsPowerOfTwo for UTerm {
+    type Output = B0;
+}
+
+impl IsPowerOfTwo for UInt<UTerm, B1> {
+    type Output = B1;
+}
+
+// An odd number greater than one is never a power of two.
+impl<U: Unsigned, B: Bit> IsPowerOfTwo for UInt<UInt<U, B>, B1> {
+    type Output = B0;
+}
+
+impl<U: Unsigned + IsPowerOfTwo> IsPowerOfTwo for UInt<U, B0> {
+    type Output = <U as IsPowerOfTwo>::Output;
+}
+
+// -----------------------------------------
+// PrevPowerOfTwo
+use crate::{PrevPow2, PrevPowerOfTwo};
+
+// 1 << log2(Self)
+impl<U, B> PrevPowerOfTwo for UInt<U, B>
+where
+    U: Unsigned,
+    B: Bit,
+    UInt<U, B>: Logarithm2,
+    U1: Shl<Log2<UInt<U, B>>>,
+{
+    type Output = Shleft<U1, Log2<UInt<U, B>>>;
+}
+
+// -----------------------------------------
+// NextPowerOfTwo
+use crate::NextPowerOfTwo;
+
+impl NextPowerOfTwo for UTerm {
+    type Output = U1;
+}
+
+impl NextPowerOfTwo for UInt<UTerm, B1> {
+    type Output = U1;
+}
+
+// General case where Self >= 2: prev_power_of_two(Self - 1) << 1
+impl<U, Bu, B> NextPowerOfTwo for UInt<UInt<U, Bu>, B>
+where
+    U: Unsigned,
+    Bu: Bit,
+    B: Bit,
+    UInt<UInt<U, Bu>, B>: Sub<B1>,
+    Sub1<UInt<UInt<U, Bu>, B>>: PrevPowerOfTwo,
+    PrevPow2<Sub1<UInt<UInt<U, Bu>, B>>>: Shl<B1>,
+{
+    type Output = Shleft<PrevPow2<Sub1<UInt<UInt<U, Bu>, B>>>, B1>;
+}
+
+// -----------------------------------------
+// AlignDown
+use crate::{AlignDown, AlignUp, AlignedDown, And};
+
+// N - (N & (A - 1))
+impl<N, A> AlignDown<A> for N
+where
+    N: Unsigned + BitAnd<Sub1<A>>,
+    A: Unsigned + PowerOfTwo + Sub<B1>,
+    N: Sub<And<N, Sub1<A>>>,
+{
+    type Output = Diff<N, And<N, Sub1<A>>>;
+}
+
+// -----------------------------------------
+// AlignUp
+
+// align_down(N + A - 1)
+impl<N, A> AlignUp<A> for N
+where
+    N: Unsigned + Add<Sub1<A>>,
+    A: Unsigned + PowerOfTwo + Sub<B1>,
+    Sum<N, Sub1<A>>: AlignDown<A>,
+{
+    type Output = AlignedDown<Sum<N, Sub1<A>>, A>;
+}
+
+// -----------------------------------------
+// CountOnes
+use crate::{CountOnes, NumOnes};
+
+impl CountOnes for UTerm {
+    type Output = U0;
+}
+
+impl<U, B> CountOnes for UInt<U, B>
+where
+    U: Unsigned + CountOnes,
+    B: Bit,
+    NumOnes<U>: Add<B>,
+{
+    type Output = Sum<NumOnes<U>, B>;
+}
+
+// -----------------------------------------
+// CountZeros
+use crate::CountZeros;
+
+// W - count_ones(N), as long as N fits in W bits
+impl<N, W> CountZeros<W> for N
+where
+    N: Unsigned + CountOnes + Len,
+    W: Unsigned + Sub<Length<N>> + Sub<NumOnes<N>>,
+{
+    type Output = Diff<W, NumOnes<N>>;
+}
+
+// -----------------------------------------
+// TrailingZeros
+use crate::{NumTrailingZeros, TrailingZeros};
+
+impl<U: Unsigned> TrailingZeros for UInt<U, B1> {
+    type Output = U0;
+}
+
+impl<U> TrailingZeros for UInt<U, B0>
+where
+    U: Unsigned + TrailingZeros,
+    NumTrailingZeros<U>: Add<B1>,
+{
+    type Output = Add1<NumTrailingZeros<U>>;
+}
+
+// -----------------------------------------
+// LeadingZeros
+use crate::LeadingZeros;
+
+// W - len(N)
+impl<N, W> LeadingZeros<W> for N
+where
+    N: Unsigned + Len,
+    W: Unsigned + Sub<Length<N>>,
+{
+    type Output = Diff<W, Length<N>>;
+}
+
+// -----------------------------------------
+// Parity
+use crate::{Parity, ParityBit, Xor};
+
+impl Parity for UTerm {
+    type Output = B0;
+}
+
+impl<U, B> Parity for UInt<U, B>
+where
+    U: Unsigned + Parity,
+    B: Bit,
+    ParityBit<U>: BitXor<B>,
+    Xor<ParityBit<U>, B>: Bit,
+{
+    type Output = Xor<ParityBit<U>, B>;
+}
+
 // -----------------------------------------
 // ToInt
 
@@ -2504,7 +3498,14 @@ where
 #[cfg(test)]
 mod tests {
     use crate::consts::*;
-    use crate::{Log2, ToInt, Unsigned};
+    use crate::{
+        assert_type_eq, tarr, AlignedDown, AlignedUp, Bit, Cbrt, CeilCbrt, CeilLog, CeilLog2,
+        CeilRoot, CeilSqrt, Choose, Clamped, DivisorList, Fact, Factors, IsEvenOut, IsNegativeOut,
+        IsOddOut, IsPositiveOut, IsPow2, IsPower, IsSquare, IsZeroOut, Log, Log2, ModExp, ModInv,
+        ModProd, ModSum, NextPow2, NumDivisors, NumLeadingZeros, NumOnes, NumTrailingZeros,
+        NumZeros, ParityBit, Perm, Phi, PrevPow2, Prime, Root, Sgn, SumOfDivisors, ToInt, Unsigned,
+        WrappingBitNot, WrappingDiff, WrappingNegate, WrappingProd, WrappingSum,
+    };
 
     #[test]
     fn log2_test() {
@@ -2550,6 +3551,256 @@ mod tests {
         // ...
     }
 
+    #[test]
+    fn log_test() {
+        assert_eq!(0, <CeilLog2<U1>>::to_u32());
+        assert_eq!(1, <CeilLog2<U2>>::to_u32());
+        assert_eq!(2, <CeilLog2<U3>>::to_u32());
+        assert_eq!(2, <CeilLog2<U4>>::to_u32());
+        assert_eq!(3, <CeilLog2<U5>>::to_u32());
+        assert_eq!(10, <CeilLog2<U1024>>::to_u32());
+        assert_eq!(10, <CeilLog2<U513>>::to_u32());
+
+        assert_eq!(0, <Log<U1, U10>>::to_u32());
+        assert_eq!(0, <Log<U9, U10>>::to_u32());
+        assert_eq!(1, <Log<U10, U10>>::to_u32());
+        assert_eq!(2, <Log<U999, U10>>::to_u32());
+        assert_eq!(3, <Log<U1000, U10>>::to_u32());
+        assert_eq!(3, <Log<U8, U2>>::to_u32());
+        assert_eq!(3, <Log<U80, U3>>::to_u32());
+        assert_eq!(4, <Log<U81, U3>>::to_u32());
+
+        assert_eq!(0, <CeilLog<U1, U10>>::to_u32());
+        assert_eq!(1, <CeilLog<U2, U10>>::to_u32());
+        assert_eq!(1, <CeilLog<U10, U10>>::to_u32());
+        assert_eq!(2, <CeilLog<U11, U10>>::to_u32());
+        assert_eq!(3, <CeilLog<U1000, U10>>::to_u32());
+        assert_eq!(4, <CeilLog<U1001, U10>>::to_u32());
+        assert_eq!(4, <CeilLog<U81, U3>>::to_u32());
+        assert_eq!(5, <CeilLog<U82, U3>>::to_u32());
+    }
+
+    #[test]
+    fn factorial_test() {
+        assert_eq!(1, <Fact<U0>>::to_u32());
+        assert_eq!(1, <Fact<U1>>::to_u32());
+        assert_eq!(2, <Fact<U2>>::to_u32());
+        assert_eq!(6, <Fact<U3>>::to_u32());
+        assert_eq!(40320, <Fact<U8>>::to_u32());
+
+        assert_eq!(1820, <Choose<U16, U4>>::to_u32());
+        assert_eq!(0, <Choose<U3, U4>>::to_u32());
+        assert_eq!(43680, <Perm<U16, U4>>::to_u32());
+    }
+
+    #[test]
+    fn prime_test() {
+        assert!(!<Prime<U0>>::to_bool());
+        assert!(!<Prime<U1>>::to_bool());
+        assert!(<Prime<U2>>::to_bool());
+        assert!(<Prime<U3>>::to_bool());
+        assert!(!<Prime<U4>>::to_bool());
+        assert!(<Prime<U5>>::to_bool());
+        assert!(!<Prime<U9>>::to_bool());
+        assert!(!<Prime<U25>>::to_bool());
+        assert!(<Prime<U97>>::to_bool());
+        assert!(!<Prime<U221>>::to_bool());
+        assert!(<Prime<U1021>>::to_bool());
+
+        assert_type_eq!(Factors<U1>, tarr![]);
+        assert_type_eq!(Factors<U2>, tarr![U2]);
+        assert_type_eq!(Factors<U8>, tarr![U2, U2, U2]);
+        assert_type_eq!(Factors<U60>, tarr![U2, U2, U3, U5]);
+        assert_type_eq!(Factors<U97>, tarr![U97]);
+        assert_type_eq!(Factors<U221>, tarr![U13, U17]);
+    }
+
+    #[test]
+    fn divisor_test() {
+        assert_eq!(1, <Phi<U1>>::to_u32());
+        assert_eq!(1, <Phi<U2>>::to_u32());
+        assert_eq!(4, <Phi<U8>>::to_u32());
+        assert_eq!(12, <Phi<U36>>::to_u32());
+        assert_eq!(96, <Phi<U97>>::to_u32());
+        assert_eq!(40, <Phi<U100>>::to_u32());
+
+        assert_type_eq!(DivisorList<U1>, tarr![U1]);
+        assert_type_eq!(DivisorList<U7>, tarr![U1, U7]);
+        assert_type_eq!(DivisorList<U16>, tarr![U1, U2, U4, U8, U16]);
+        assert_type_eq!(DivisorList<U18>, tarr![U1, U2, U3, U6, U9, U18]);
+
+        assert_eq!(1, <NumDivisors<U1>>::to_u32());
+        assert_eq!(2, <NumDivisors<U13>>::to_u32());
+        assert_eq!(9, <NumDivisors<U36>>::to_u32());
+        assert_eq!(12, <NumDivisors<U60>>::to_u32());
+
+        assert_eq!(1, <SumOfDivisors<U1>>::to_u32());
+        assert_eq!(14, <SumOfDivisors<U13>>::to_u32());
+        assert_eq!(28, <SumOfDivisors<U12>>::to_u32());
+        assert_eq!(56, <SumOfDivisors<U28>>::to_u32());
+    }
+
+    #[test]
+    fn modular_test() {
+        assert_eq!(4, <ModSum<U5, U6, U7>>::to_u32());
+        assert_eq!(0, <ModSum<U3, U4, U7>>::to_u32());
+        assert_eq!(0, <ModSum<U3, U4, U1>>::to_u32());
+        assert_eq!(2, <ModProd<U5, U6, U7>>::to_u32());
+        assert_eq!(0, <ModProd<U0, U6, U7>>::to_u32());
+        assert_eq!(1, <ModProd<U1000, U1000, U999>>::to_u32());
+
+        assert_eq!(1, <ModExp<U3, U0, U7>>::to_u32());
+        assert_eq!(0, <ModExp<U3, U0, U1>>::to_u32());
+        assert_eq!(6, <ModExp<U3, U3, U7>>::to_u32());
+        assert_eq!(3, <ModExp<U3, U1000, U13>>::to_u32());
+        // 3 is a primitive root modulo 257.
+        assert_eq!(256, <ModExp<U3, U128, U257>>::to_u32());
+
+        assert_eq!(5, <ModInv<U3, U7>>::to_u32());
+        assert_eq!(1, <ModInv<U1, U7>>::to_u32());
+        assert_eq!(0, <ModInv<U0, U1>>::to_u32());
+        assert_eq!(5, <ModInv<U10, U7>>::to_u32());
+        assert_eq!(129, <ModInv<U2, U257>>::to_u32());
+        assert_eq!(256, <ModInv<U256, U257>>::to_u32());
+    }
+
+    #[test]
+    fn root_test() {
+        assert_eq!(0, <Root<U0, U3>>::to_u32());
+        assert_eq!(1, <Root<U1, U3>>::to_u32());
+        assert_eq!(1, <Root<U7, U3>>::to_u32());
+        assert_eq!(2, <Root<U8, U3>>::to_u32());
+        assert_eq!(4, <Root<U124, U3>>::to_u32());
+        assert_eq!(5, <Root<U125, U3>>::to_u32());
+        assert_eq!(3, <Root<U100, U4>>::to_u32());
+        assert_eq!(100, <Root<U100, U1>>::to_u32());
+        assert_eq!(10, <Root<U100, U2>>::to_u32());
+        assert_eq!(10, <Cbrt<U1000>>::to_u32());
+
+        assert_eq!(0, <CeilRoot<U0, U3>>::to_u32());
+        assert_eq!(2, <CeilRoot<U8, U3>>::to_u32());
+        assert_eq!(3, <CeilRoot<U9, U3>>::to_u32());
+        assert_eq!(4, <CeilSqrt<U16>>::to_u32());
+        assert_eq!(5, <CeilSqrt<U17>>::to_u32());
+        assert_eq!(10, <CeilCbrt<U1000>>::to_u32());
+        assert_eq!(11, <CeilCbrt<U1001>>::to_u32());
+
+        assert!(<IsSquare<U0>>::to_bool());
+        assert!(<IsSquare<U1>>::to_bool());
+        assert!(!<IsSquare<U2>>::to_bool());
+        assert!(<IsSquare<U144>>::to_bool());
+        assert!(!<IsSquare<U145>>::to_bool());
+
+        assert!(<IsPower<U64, U3>>::to_bool());
+        assert!(!<IsPower<U63, U3>>::to_bool());
+        assert!(<IsPower<U81, U4>>::to_bool());
+        assert!(<IsPower<U7, U1>>::to_bool());
+    }
+
+    #[test]
+    fn predicate_test() {
+        assert_type_eq!(Sgn<U0>, Z0);
+        assert_type_eq!(Sgn<U6>, P1);
+        assert!(!<IsNegativeOut<U6>>::to_bool());
+        assert!(!<IsPositiveOut<U0>>::to_bool());
+        assert!(<IsPositiveOut<U1>>::to_bool());
+        assert!(<IsZeroOut<U0>>::to_bool());
+        assert!(!<IsZeroOut<U8>>::to_bool());
+        assert!(<IsEvenOut<U0>>::to_bool());
+        assert!(<IsEvenOut<U8>>::to_bool());
+        assert!(!<IsEvenOut<U9>>::to_bool());
+        assert!(!<IsOddOut<U0>>::to_bool());
+        assert!(<IsOddOut<U9>>::to_bool());
+    }
+
+    #[test]
+    fn wrapping_test() {
+        assert_eq!(<WrappingSum<U200, U100, U8>>::to_u32(), 44);
+        assert_eq!(<WrappingSum<U3, U4, U8>>::to_u32(), 7);
+        assert_eq!(<WrappingSum<U3, U4, U0>>::to_u32(), 0);
+        assert_eq!(<WrappingDiff<U0, U1, U12>>::to_u32(), 4095);
+        assert_eq!(<WrappingDiff<U7, U3, U4>>::to_u32(), 4);
+        assert_eq!(<WrappingDiff<U7, U1000, U8>>::to_u32(), 31);
+        assert_eq!(<WrappingProd<U16, U16, U8>>::to_u32(), 0);
+        assert_eq!(<WrappingProd<U15, U17, U8>>::to_u32(), 255);
+        assert_eq!(<WrappingNegate<U0, U8>>::to_u32(), 0);
+        assert_eq!(<WrappingNegate<U6, U4>>::to_u32(), 10);
+        assert_eq!(<WrappingBitNot<U0, U16>>::to_u32(), 0xffff);
+        assert_eq!(<WrappingBitNot<U1023, U8>>::to_u32(), 0);
+        assert_eq!(<WrappingBitNot<U5, U3>>::to_u32(), 2);
+    }
+
+    #[test]
+    fn clamp_test() {
+        assert_type_eq!(Clamped<U1, U3, U7>, U3);
+        assert_type_eq!(Clamped<U0, U3, U7>, U3);
+        assert_type_eq!(Clamped<U5, U3, U7>, U5);
+        assert_type_eq!(Clamped<U3, U3, U7>, U3);
+        assert_type_eq!(Clamped<U7, U3, U7>, U7);
+        assert_type_eq!(Clamped<U9, U3, U7>, U7);
+        assert_type_eq!(Clamped<U2, U4, U4>, U4);
+        assert_type_eq!(Clamped<U4, U4, U4>, U4);
+        assert_type_eq!(Clamped<U6, U4, U4>, U4);
+    }
+
+    #[test]
+    fn power_of_two_test() {
+        assert!(!<IsPow2<U0>>::to_bool());
+        assert!(<IsPow2<U1>>::to_bool());
+        assert!(<IsPow2<U2>>::to_bool());
+        assert!(!<IsPow2<U3>>::to_bool());
+        assert!(<IsPow2<U64>>::to_bool());
+        assert!(!<IsPow2<U96>>::to_bool());
+
+        assert_eq!(1, <NextPow2<U0>>::to_u32());
+        assert_eq!(1, <NextPow2<U1>>::to_u32());
+        assert_eq!(2, <NextPow2<U2>>::to_u32());
+        assert_eq!(4, <NextPow2<U3>>::to_u32());
+        assert_eq!(64, <NextPow2<U64>>::to_u32());
+        assert_eq!(128, <NextPow2<U65>>::to_u32());
+
+        assert_eq!(1, <PrevPow2<U1>>::to_u32());
+        assert_eq!(2, <PrevPow2<U3>>::to_u32());
+        assert_eq!(64, <PrevPow2<U64>>::to_u32());
+        assert_eq!(64, <PrevPow2<U127>>::to_u32());
+
+        assert_eq!(0, <AlignedUp<U0, U8>>::to_u32());
+        assert_eq!(8, <AlignedUp<U1, U8>>::to_u32());
+        assert_eq!(8, <AlignedUp<U8, U8>>::to_u32());
+        assert_eq!(16, <AlignedUp<U9, U8>>::to_u32());
+        assert_eq!(9, <AlignedUp<U9, U1>>::to_u32());
+
+        assert_eq!(0, <AlignedDown<U7, U8>>::to_u32());
+        assert_eq!(8, <AlignedDown<U8, U8>>::to_u32());
+        assert_eq!(8, <AlignedDown<U15, U8>>::to_u32());
+    }
+
+    #[test]
+    fn bit_count_test() {
+        assert_eq!(0, <NumOnes<U0>>::to_u32());
+        assert_eq!(1, <NumOnes<U8>>::to_u32());
+        assert_eq!(4, <NumOnes<U15>>::to_u32());
+        assert_eq!(2, <NumOnes<U40>>::to_u32());
+
+        assert_eq!(8, <NumZeros<U0, U8>>::to_u32());
+        assert_eq!(4, <NumZeros<U15, U8>>::to_u32());
+        assert_eq!(0, <NumZeros<U255, U8>>::to_u32());
+
+        assert_eq!(0, <NumTrailingZeros<U1>>::to_u32());
+        assert_eq!(3, <NumTrailingZeros<U8>>::to_u32());
+        assert_eq!(3, <NumTrailingZeros<U40>>::to_u32());
+        assert_eq!(12, <NumTrailingZeros<U4096>>::to_u32());
+
+        assert_eq!(32, <NumLeadingZeros<U0, U32>>::to_u32());
+        assert_eq!(31, <NumLeadingZeros<U1, U32>>::to_u32());
+        assert_eq!(0, <NumLeadingZeros<U128, U8>>::to_u32());
+
+        assert!(!<ParityBit<U0>>::to_bool());
+        assert!(<ParityBit<U1>>::to_bool());
+        assert!(!<ParityBit<U3>>::to_bool());
+        assert!(<ParityBit<U7>>::to_bool());
+    }
+
     #[test]
     fn uint_toint_test() {
         // i8